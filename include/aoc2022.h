@@ -0,0 +1,44 @@
+#ifndef AOC2022_H
+#define AOC2022_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum aoc_status {
+    AOC_STATUS_OK = 0,
+    AOC_STATUS_NULL_INPUT = 1,
+    AOC_STATUS_UNKNOWN_DAY = 2,
+    AOC_STATUS_INVALID_PART = 3,
+    AOC_STATUS_INVALID_UTF8 = 4,
+    AOC_STATUS_SOLVER_PANICKED = 5,
+} aoc_status;
+
+/*
+ * On success `answer` holds the NUL-terminated answer and `message` is NULL.
+ * On failure `answer` is NULL and `message` describes the error.
+ * Both strings are owned by the result.
+ */
+typedef struct aoc_result {
+    aoc_status status;
+    char *answer;
+    char *message;
+} aoc_result;
+
+/*
+ * Solves `part` (1 or 2) of `day` for the `input_len` bytes of UTF-8 at `input`.
+ * The returned result is never NULL and must be released with aoc_result_free.
+ */
+aoc_result *aoc_solve(uint32_t day, uint32_t part, const char *input, size_t input_len);
+
+/* Releases a result returned by aoc_solve. Passing NULL is a no-op. */
+void aoc_result_free(aoc_result *result);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC2022_H */
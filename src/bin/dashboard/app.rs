@@ -0,0 +1,296 @@
+use std::{
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc2022_rs::registry::{self, Day, Part, Solver};
+use ratatui::crossterm::event::KeyCode;
+
+/// Solvers run on threads with this name, so that their panics are not printed over the UI.
+pub const SOLVER_THREAD: &str = "solver";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    NotRun,
+    Running,
+    Answer { answer: String, elapsed: Duration },
+    Error { message: String, elapsed: Duration },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Job {
+    Part(Part),
+    Render,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    Answers,
+    Input,
+    Render,
+}
+
+struct Finished {
+    day: usize,
+    job: Job,
+    status: Status,
+}
+
+pub struct DayState {
+    pub day: &'static Day,
+    pub parts: [Status; 2],
+    pub render: Status,
+}
+
+impl DayState {
+    pub fn status(&self, job: Job) -> &Status {
+        match job {
+            Job::Part(Part::One) => &self.parts[0],
+            Job::Part(Part::Two) => &self.parts[1],
+            Job::Render => &self.render,
+        }
+    }
+
+    fn status_mut(&mut self, job: Job) -> &mut Status {
+        match job {
+            Job::Part(Part::One) => &mut self.parts[0],
+            Job::Part(Part::Two) => &mut self.parts[1],
+            Job::Render => &mut self.render,
+        }
+    }
+
+    fn solver(&self, job: Job) -> Option<Solver> {
+        match job {
+            Job::Part(part) => Some(self.day.solver(part)),
+            Job::Render => self.day.render,
+        }
+    }
+}
+
+pub struct App {
+    pub days: Vec<DayState>,
+    pub selected: usize,
+    pub view: View,
+    pub scroll: u16,
+    pub quit: bool,
+    sender: Sender<Finished>,
+    receiver: Receiver<Finished>,
+}
+
+impl App {
+    pub fn new(days: &'static [Day]) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            days: days
+                .iter()
+                .map(|day| DayState {
+                    day,
+                    parts: [Status::NotRun, Status::NotRun],
+                    render: Status::NotRun,
+                })
+                .collect(),
+            selected: 0,
+            view: View::Answers,
+            scroll: 0,
+            quit: false,
+            sender,
+            receiver,
+        }
+    }
+
+    pub fn selected(&self) -> Option<&DayState> {
+        self.days.get(self.selected)
+    }
+
+    fn start(&mut self, index: usize, job: Job) {
+        let state = &mut self.days[index];
+        let Some(solver) = state.solver(job) else {
+            return;
+        };
+        if *state.status(job) == Status::Running {
+            return;
+        }
+        *state.status_mut(job) = Status::Running;
+
+        let input = state.day.input;
+        let sender = self.sender.clone();
+        thread::Builder::new()
+            .name(SOLVER_THREAD.to_owned())
+            .spawn(move || {
+                let start = Instant::now();
+                let result = registry::catch_panic(solver, input);
+                let elapsed = start.elapsed();
+                let status = match result {
+                    Ok(answer) => Status::Answer { answer, elapsed },
+                    Err(message) => Status::Error { message, elapsed },
+                };
+                // The receiver only goes away when the dashboard is closing.
+                let _ = sender.send(Finished {
+                    day: index,
+                    job,
+                    status,
+                });
+            })
+            .expect("failed to spawn a solver thread");
+    }
+
+    pub fn run_selected(&mut self) {
+        if self.selected < self.days.len() {
+            for part in Part::PARTS {
+                self.start(self.selected, Job::Part(part));
+            }
+        }
+    }
+
+    pub fn run_all(&mut self) {
+        for index in 0..self.days.len() {
+            for part in Part::PARTS {
+                self.start(index, Job::Part(part));
+            }
+        }
+    }
+
+    /// Collects the results of finished solvers, returning whether there were any.
+    pub fn receive(&mut self) -> bool {
+        let mut received = false;
+        while let Ok(Finished { day, job, status }) = self.receiver.try_recv() {
+            *self.days[day].status_mut(job) = status;
+            received = true;
+        }
+        received
+    }
+
+    fn toggle_view(&mut self, view: View) {
+        self.scroll = 0;
+        if self.view == view {
+            self.view = View::Answers;
+            return;
+        }
+        self.view = view;
+        if view == View::Render
+            && self
+                .selected()
+                .is_some_and(|state| state.render == Status::NotRun)
+        {
+            self.start(self.selected, Job::Render);
+        }
+    }
+
+    fn select(&mut self, selected: usize) {
+        if selected < self.days.len() && selected != self.selected {
+            self.selected = selected;
+            self.scroll = 0;
+            if self.view == View::Render {
+                self.view = View::Answers;
+            }
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Up | KeyCode::Char('k') => self.select(self.selected.saturating_sub(1)),
+            KeyCode::Down | KeyCode::Char('j') => self.select(self.selected + 1),
+            KeyCode::Enter | KeyCode::Char('r') => self.run_selected(),
+            KeyCode::Char('a') => self.run_all(),
+            KeyCode::Char('i') => self.toggle_view(View::Input),
+            KeyCode::Char('v') => self.toggle_view(View::Render),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::time::{Duration, Instant};
+
+    use aoc2022_rs::registry::{Day, Part};
+    use ratatui::crossterm::event::KeyCode;
+
+    use crate::app::{App, Job, Status, View};
+
+    pub(crate) static DAYS: &[Day] = &[
+        Day {
+            number: 1,
+            title: "Echo",
+            input: "hello",
+            part1: |input| input.to_owned(),
+            part2: |input| input.len().to_string(),
+            render: Some(str::to_uppercase),
+            report: None,
+            variants: &[],
+        },
+        Day {
+            number: 2,
+            title: "Broken",
+            input: "",
+            part1: |_| panic!("no input"),
+            part2: |_| String::new(),
+            render: None,
+            report: None,
+            variants: &[],
+        },
+    ];
+
+    fn wait(app: &mut App) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while app
+            .days
+            .iter()
+            .any(|state| state.parts.contains(&Status::Running) || state.render == Status::Running)
+        {
+            assert!(Instant::now() < deadline, "solvers did not finish");
+            app.receive();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn run_all() {
+        let mut app = App::new(DAYS);
+        app.handle_key(KeyCode::Char('a'));
+        wait(&mut app);
+        assert!(
+            matches!(app.days[0].status(Job::Part(Part::One)), Status::Answer { answer, .. } if answer == "hello")
+        );
+        assert!(matches!(&app.days[0].parts[1], Status::Answer { answer, .. } if answer == "5"));
+        assert!(
+            matches!(&app.days[1].parts[0], Status::Error { message, .. } if message == "no input")
+        );
+        assert_eq!(app.days[0].render, Status::NotRun);
+    }
+
+    #[test]
+    fn navigation() {
+        let mut app = App::new(DAYS);
+        app.handle_key(KeyCode::Up);
+        assert_eq!(app.selected, 0);
+        app.handle_key(KeyCode::Char('v'));
+        assert_eq!(app.view, View::Render);
+        wait(&mut app);
+        assert!(matches!(&app.days[0].render, Status::Answer { answer, .. } if answer == "HELLO"));
+
+        app.handle_key(KeyCode::Down);
+        app.handle_key(KeyCode::Down);
+        assert_eq!(app.selected, 1);
+        assert_eq!(app.view, View::Answers);
+        app.handle_key(KeyCode::Char('i'));
+        assert_eq!(app.view, View::Input);
+        app.handle_key(KeyCode::Char('i'));
+        assert_eq!(app.view, View::Answers);
+
+        app.handle_key(KeyCode::Enter);
+        wait(&mut app);
+        assert!(app.days[0]
+            .parts
+            .iter()
+            .all(|status| *status == Status::NotRun));
+        assert!(matches!(&app.days[1].parts[1], Status::Answer { .. }));
+
+        app.handle_key(KeyCode::Char('q'));
+        assert!(app.quit);
+    }
+}
@@ -0,0 +1,42 @@
+#![warn(clippy::pedantic)]
+
+mod app;
+mod ui;
+
+use std::{io, panic, thread, time::Duration};
+
+use aoc2022_rs::registry;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+
+use crate::app::{App, SOLVER_THREAD};
+
+fn main() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+
+    // Solver panics are reported in the UI instead.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().name() != Some(SOLVER_THREAD) {
+            hook(info);
+        }
+    }));
+
+    let mut app = App::new(registry::DAYS);
+    let result = (|| {
+        while !app.quit {
+            app.receive();
+            terminal.draw(|frame| ui::draw(frame, &app))?;
+            if event::poll(Duration::from_millis(50))? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press {
+                        app.handle_key(key.code);
+                    }
+                }
+            }
+        }
+        Ok(())
+    })();
+
+    ratatui::restore();
+    result
+}
@@ -0,0 +1,181 @@
+use std::time::Duration;
+
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::app::{App, DayState, Job, Status, View};
+
+const HELP: &str = "↑/↓ select  ⏎/r run  a run all  i input  v render  PgUp/PgDn scroll  q quit";
+
+fn format_elapsed(elapsed: Duration) -> String {
+    if elapsed.as_secs() > 0 {
+        format!("{:.2}s", elapsed.as_secs_f64())
+    } else {
+        format!("{:.1}ms", elapsed.as_secs_f64() * 1000.0)
+    }
+}
+
+fn status_cell(status: &Status) -> Cell<'static> {
+    match status {
+        Status::NotRun => Cell::from("not run").style(Style::default().fg(Color::DarkGray)),
+        Status::Running => Cell::from("running…").style(Style::default().fg(Color::Yellow)),
+        Status::Answer { answer, elapsed } => {
+            // Multi-line answers only fit in the details pane.
+            let answer = if answer.contains('\n') {
+                "(see details)"
+            } else {
+                answer
+            };
+            Cell::from(format!("{answer} ({})", format_elapsed(*elapsed)))
+                .style(Style::default().fg(Color::Green))
+        }
+        Status::Error { elapsed, .. } => {
+            Cell::from(format!("error ({})", format_elapsed(*elapsed)))
+                .style(Style::default().fg(Color::Red))
+        }
+    }
+}
+
+fn status_text(status: &Status) -> Text<'static> {
+    match status {
+        Status::NotRun => Text::from("not run"),
+        Status::Running => Text::from("running…"),
+        Status::Answer { answer, elapsed } => {
+            let mut text = Text::from(format!("answered in {}", format_elapsed(*elapsed)));
+            text.extend(Text::styled(
+                answer.clone(),
+                Style::default().fg(Color::Green),
+            ));
+            text
+        }
+        Status::Error { message, elapsed } => {
+            let mut text = Text::from(format!("failed after {}", format_elapsed(*elapsed)));
+            text.extend(Text::styled(
+                message.clone(),
+                Style::default().fg(Color::Red),
+            ));
+            text
+        }
+    }
+}
+
+fn details(state: &DayState, view: View) -> (String, Text<'static>) {
+    let day = state.day;
+    match view {
+        View::Answers => {
+            let mut text = Text::default();
+            for (index, status) in state.parts.iter().enumerate() {
+                if index > 0 {
+                    text.push_line(Line::default());
+                }
+                text.push_line(Line::styled(
+                    format!("Part {}", index + 1),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ));
+                text.extend(status_text(status));
+            }
+            (format!("Day {}: {}", day.number, day.title), text)
+        }
+        View::Input => (format!("Day {} input", day.number), Text::from(day.input)),
+        View::Render => {
+            let text = if day.render.is_some() {
+                match state.status(Job::Render) {
+                    Status::Answer { answer, .. } => Text::from(answer.clone()),
+                    status => status_text(status),
+                }
+            } else {
+                Text::from("this day has no visual output")
+            };
+            (format!("Day {} render", day.number), text)
+        }
+    }
+}
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [main, footer] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+    let [list, pane] =
+        Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)]).areas(main);
+
+    let rows = app.days.iter().map(|state| {
+        Row::new([
+            Cell::from(format!("Day {}", state.day.number)),
+            Cell::from(state.day.title),
+            status_cell(&state.parts[0]),
+            status_cell(&state.parts[1]),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(6),
+            Constraint::Fill(2),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(
+        Row::new(["", "Title", "Part 1", "Part 2"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+    .block(Block::default().borders(Borders::ALL).title("Days"));
+    let mut table_state = TableState::default().with_selected(Some(app.selected));
+    frame.render_stateful_widget(table, list, &mut table_state);
+
+    if let Some(state) = app.selected() {
+        let (title, text) = details(state, app.view);
+        let paragraph = Paragraph::new(text)
+            .scroll((app.scroll, 0))
+            .block(Block::default().borders(Borders::ALL).title(title));
+        frame.render_widget(paragraph, pane);
+    }
+
+    frame.render_widget(
+        Line::from(Span::styled(HELP, Style::default().fg(Color::DarkGray))),
+        footer,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, crossterm::event::KeyCode, Terminal};
+
+    use crate::{
+        app::{tests::DAYS, App},
+        ui::draw,
+    };
+
+    fn screen(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 12)).unwrap();
+        terminal.draw(|frame| draw(frame, app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width.into())
+            .map(|line| {
+                line.iter()
+                    .map(ratatui::buffer::Cell::symbol)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn lists_days() {
+        let mut app = App::new(DAYS);
+        let screen = screen(&app);
+        assert!(screen.contains("Day 1"));
+        assert!(screen.contains("Broken"));
+        assert!(screen.contains("not run"));
+
+        app.handle_key(KeyCode::Char('i'));
+        assert!(self::screen(&app).contains("hello"));
+    }
+}
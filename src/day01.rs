@@ -1,19 +1,123 @@
-fn parse(input: &str) -> impl Iterator<Item = u32> + '_ {
-    input
-        // Each elf is separated by two new lines,
-        // so we split by that first,
-        // returning an iterator of each elf's calories in string form.
-        .split("\r\n\r\n")
-        .map(|elf| {
-            // We split each line.
-            elf.split("\r\n")
-                // Assuming we only get blank lines as errors,
-                // we simply just ignore then,
-                // only taking those that can be parsed.
-                .filter_map(|calories| calories.trim().parse::<u32>().ok())
-                // We only need the sum of all calories for each elf.
-                .sum()
-        })
+use std::{cmp::Reverse, collections::BinaryHeap, error::Error, fmt};
+
+/// What is wrong with a line that could not be counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Problem {
+    /// The line is not a number at all, like `12a4`.
+    NotANumber,
+    /// The number does not fit in a `u32`.
+    TooLarge,
+    /// The number fits, but adding it to the elf's total does not.
+    TotalOverflow,
+}
+
+/// A line that was not counted, along with why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Counting from 1, like an editor does.
+    pub line: usize,
+    pub content: String,
+    pub problem: Problem,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let problem = match self.problem {
+            Problem::NotANumber => "is not a number of calories",
+            Problem::TooLarge => "is too many calories for a single item",
+            Problem::TotalOverflow => "overflows the total calories of its elf",
+        };
+        write!(f, "line {}: `{}` {problem}", self.line, self.content)
+    }
+}
+
+/// Every line that strict parsing refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} invalid line(s)", self.diagnostics.len())?;
+        for diagnostic in &self.diagnostics {
+            write!(f, "\n{diagnostic}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Reads the calories of every elf, skipping the lines that cannot be counted
+/// and reporting each of them.
+fn scan(input: &str) -> (Vec<Vec<u32>>, Vec<Diagnostic>) {
+    let mut elves = Vec::new();
+    let mut diagnostics = Vec::new();
+    // The elf being read, which only counts as an elf once it has a line.
+    let mut elf: Option<(Vec<u32>, u32)> = None;
+    // `lines` handles both "\n" and "\r\n" line endings.
+    for (index, line) in input.lines().enumerate() {
+        let content = line.trim();
+        // Each elf is separated by a blank line.
+        if content.is_empty() {
+            elves.extend(elf.take().map(|(items, _)| items));
+            continue;
+        }
+        let (items, total) = elf.get_or_insert_with(|| (Vec::new(), 0));
+
+        let problem = match content.parse::<u32>() {
+            Ok(calories) => match total.checked_add(calories) {
+                Some(sum) => {
+                    *total = sum;
+                    items.push(calories);
+                    continue;
+                }
+                None => Problem::TotalOverflow,
+            },
+            // Only digits, so the number must have been too large.
+            Err(_) if content.bytes().all(|byte| byte.is_ascii_digit()) => Problem::TooLarge,
+            Err(_) => Problem::NotANumber,
+        };
+        diagnostics.push(Diagnostic {
+            line: index + 1,
+            content: content.to_owned(),
+            problem,
+        });
+    }
+    elves.extend(elf.map(|(items, _)| items));
+    (elves, diagnostics)
+}
+
+/// Reads the calories of every elf, refusing the input if any line cannot be counted.
+///
+/// # Errors
+///
+/// Returns every line that is not a number, or that makes a total overflow.
+pub fn parse_strict(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    match scan(input) {
+        (elves, diagnostics) if diagnostics.is_empty() => Ok(elves),
+        (_, diagnostics) => Err(ParseError { diagnostics }),
+    }
+}
+
+/// Reads the calories of every elf, skipping the lines that cannot be counted
+/// and listing them instead.
+pub fn parse_lenient(input: &str) -> (Vec<Vec<u32>>, Vec<Diagnostic>) {
+    scan(input)
+}
+
+fn parse_elves(input: &str) -> Vec<Vec<u32>> {
+    // The solvers have always skipped lines they cannot read,
+    // so they keep doing that and leave the diagnostics to the report.
+    parse_lenient(input).0
+}
+
+fn parse(input: &str) -> impl Iterator<Item = u32> {
+    // We only need the sum of all calories for each elf.
+    parse_elves(input)
+        .into_iter()
+        .map(|elf| elf.into_iter().sum())
 }
 
 pub fn solve_part1(input: &str) -> u32 {
@@ -22,21 +126,350 @@ pub fn solve_part1(input: &str) -> u32 {
     parse(input).max().unwrap()
 }
 
+/// The `k` elves carrying the most calories, as (elf index, total) pairs,
+/// from the most calories to the least.
+pub fn top_k(input: &str, k: usize) -> Vec<(usize, u32)> {
+    // A min-heap holding the best `k` elves seen so far,
+    // so the weakest of them is always on top, ready to be replaced.
+    // On equal totals the later elf counts as the weaker one.
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for (index, total) in parse(input).enumerate() {
+        heap.push(Reverse((total, Reverse(index))));
+        // Pushing and then popping keeps the heap at `k` elves,
+        // making this O(n log k) rather than sorting every elf.
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    // Sorting the heap in ascending order of `Reverse` gives the strongest elves first.
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((total, Reverse(index)))| (index, total))
+        .collect()
+}
+
 pub fn solve_part2(input: &str) -> u32 {
-    // You cannot sort an iterator.
-    // Therefore, we convert this into a Vec and sort it.
-    let mut vec: Vec<u32> = parse(input).collect();
-    // This sorts from smallest to largest.
-    vec.sort_unstable();
-    // To find the three elves with the largest amount of calories,
-    // we reverse the iterator to take the first three numbers,
-    // and then sum them.
-    vec.into_iter().rev().take(3).sum()
+    // We only need the totals of the three elves with the most calories.
+    top_k(input, 3).into_iter().map(|(_, total)| total).sum()
+}
+
+/// How the calories are spread across the elves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalorieStats {
+    /// The total calories of each elf, from the fewest to the most.
+    totals: Vec<u32>,
+    /// The number of items each elf carries, in the order of the input.
+    items: Vec<usize>,
+}
+
+impl CalorieStats {
+    /// Returns `None` when there are no elves to describe.
+    pub fn new(elves: &[Vec<u32>]) -> Option<Self> {
+        if elves.is_empty() {
+            return None;
+        }
+        let mut totals: Vec<u32> = elves.iter().map(|elf| elf.iter().sum()).collect();
+        // Sorting once up front makes the median and the percentiles simple lookups.
+        totals.sort_unstable();
+        Some(CalorieStats {
+            totals,
+            items: elves.iter().map(Vec::len).collect(),
+        })
+    }
+
+    pub fn count(&self) -> usize {
+        self.totals.len()
+    }
+
+    pub fn minimum(&self) -> u32 {
+        self.totals[0]
+    }
+
+    pub fn maximum(&self) -> u32 {
+        self.totals[self.totals.len() - 1]
+    }
+
+    pub fn mean(&self) -> f64 {
+        let sum: f64 = self.totals.iter().copied().map(f64::from).sum();
+        sum / f64::from(u32::try_from(self.count()).unwrap())
+    }
+
+    pub fn median(&self) -> f64 {
+        let middle = self.count() / 2;
+        if self.count().is_multiple_of(2) {
+            // With an even number of elves, the median lies between the middle two.
+            f64::midpoint(
+                f64::from(self.totals[middle - 1]),
+                f64::from(self.totals[middle]),
+            )
+        } else {
+            f64::from(self.totals[middle])
+        }
+    }
+
+    /// The smallest total that at least `percent` percent of the elves do not exceed.
+    pub fn percentile(&self, percent: u8) -> u32 {
+        assert!(percent <= 100, "percentile above 100");
+        let rank = (usize::from(percent) * self.count()).div_ceil(100);
+        self.totals[rank.saturating_sub(1)]
+    }
+
+    /// The population standard deviation, as every elf is accounted for.
+    pub fn standard_deviation(&self) -> f64 {
+        let mean = self.mean();
+        let variance: f64 = self
+            .totals
+            .iter()
+            .map(|&total| (f64::from(total) - mean).powi(2))
+            .sum::<f64>()
+            / f64::from(u32::try_from(self.count()).unwrap());
+        variance.sqrt()
+    }
+
+    /// The fewest, mean and most items carried by a single elf.
+    pub fn items_per_elf(&self) -> (usize, f64, usize) {
+        let sum: usize = self.items.iter().sum();
+        let mean = f64::from(u32::try_from(sum).unwrap())
+            / f64::from(u32::try_from(self.items.len()).unwrap());
+        (
+            *self.items.iter().min().unwrap(),
+            mean,
+            *self.items.iter().max().unwrap(),
+        )
+    }
+
+    /// One line per bucket of equal width, with a bar as long as the share of elves in it.
+    pub fn histogram(&self, buckets: u32, width: usize) -> String {
+        let (minimum, maximum) = (self.minimum(), self.maximum());
+        // Every bucket spans at least one calorie, so there are never more buckets than values.
+        // Counted in `u64`, as the span of a `u32` can be one more than it holds.
+        let span = u64::from(maximum - minimum) + 1;
+        let buckets = u64::from(buckets).clamp(1, span);
+        let mut counts = vec![0_usize; usize::try_from(buckets).unwrap()];
+        for &total in &self.totals {
+            let bucket = u64::from(total - minimum) * buckets / span;
+            counts[usize::try_from(bucket).unwrap()] += 1;
+        }
+
+        let largest = *counts.iter().max().unwrap();
+        let bound = |bucket: u64| u64::from(minimum) + bucket * span / buckets;
+        (0..buckets)
+            .zip(&counts)
+            .map(|(bucket, &count)| {
+                let bar = "#".repeat(count * width / largest);
+                let (from, to) = (bound(bucket), bound(bucket + 1) - 1);
+                format!("{from:>7}..={to:<7} |{bar} {count}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Display for CalorieStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Elves: {}", self.count())?;
+        writeln!(
+            f,
+            "Calories: min {}, max {}, mean {:.1}, median {:.1}, standard deviation {:.1}",
+            self.minimum(),
+            self.maximum(),
+            self.mean(),
+            self.median(),
+            self.standard_deviation()
+        )?;
+        let percentiles: Vec<String> = [10, 25, 50, 75, 90]
+            .into_iter()
+            .map(|percent| format!("p{percent} {}", self.percentile(percent)))
+            .collect();
+        writeln!(f, "Percentiles: {}", percentiles.join(", "))?;
+        let (fewest, mean, most) = self.items_per_elf();
+        writeln!(f, "Items per elf: min {fewest}, max {most}, mean {mean:.1}")?;
+        write!(f, "Histogram:\n{}", self.histogram(10, 40))
+    }
+}
+
+/// Describes the calories of every elf, rather than only the top ones.
+pub fn report(input: &str) -> String {
+    // A report is still useful for a messy input,
+    // as long as it says what it left out.
+    let (elves, error) = match parse_strict(input) {
+        Ok(elves) => (elves, None),
+        Err(error) => (parse_lenient(input).0, Some(error)),
+    };
+    let Some(stats) = CalorieStats::new(&elves) else {
+        return "No elves.".to_owned();
+    };
+
+    // Sharing the snacks out evenly among the same elves,
+    // exactly if there are few enough of them.
+    let items = elves.concat();
+    let strategy = if items.len() <= EXACT_ITEMS {
+        Strategy::Exact
+    } else {
+        Strategy::Greedy
+    };
+    let balanced = balance(&items, elves.len(), strategy);
+    let report = format!(
+        "{stats}\nRebalanced: largest load {}, imbalance {}",
+        balanced.largest_load(),
+        balanced.imbalance()
+    );
+    match error {
+        Some(error) => format!("{report}\nSkipped {error}"),
+        None => report,
+    }
+}
+
+/// The most items the report shares out with [`Strategy::Exact`].
+const EXACT_ITEMS: usize = 16;
+
+/// How to search for the most even way to share the snacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Longest processing time first: hand the largest remaining item to the lightest elf.
+    /// Fast, and never more than a third above the best possible largest load.
+    Greedy,
+    /// Branch and bound over every assignment, starting from the greedy one.
+    /// Exponential in the number of items, so only meant for small inputs.
+    Exact,
+}
+
+/// The items each elf carries after sharing them out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    pub loads: Vec<Vec<u32>>,
+}
+
+impl Assignment {
+    /// The totals can exceed a `u32` once many items end up with one elf.
+    pub fn totals(&self) -> Vec<u64> {
+        self.loads
+            .iter()
+            .map(|load| load.iter().copied().map(u64::from).sum())
+            .collect()
+    }
+
+    pub fn largest_load(&self) -> u64 {
+        self.totals().into_iter().max().unwrap_or_default()
+    }
+
+    /// The difference between the heaviest and the lightest elf.
+    pub fn imbalance(&self) -> u64 {
+        let totals = self.totals();
+        let lightest = totals.iter().min().copied().unwrap_or_default();
+        self.largest_load() - lightest
+    }
+}
+
+impl fmt::Display for Assignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (elf, (load, total)) in self.loads.iter().zip(self.totals()).enumerate() {
+            let items: Vec<String> = load.iter().map(ToString::to_string).collect();
+            writeln!(f, "Elf {}: {total} ({})", elf + 1, items.join(" + "))?;
+        }
+        write!(
+            f,
+            "Largest load: {}, imbalance: {}",
+            self.largest_load(),
+            self.imbalance()
+        )
+    }
+}
+
+/// Shares `items` out among `elves` so that the largest load is as small as possible.
+pub fn balance(items: &[u32], elves: usize, strategy: Strategy) -> Assignment {
+    assert!(elves > 0, "cannot share snacks among no elves");
+    // Placing the largest items first leaves the small ones to even things out,
+    // for both strategies.
+    let mut sorted = items.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+
+    let greedy = lpt(&sorted, elves);
+    let owners = match strategy {
+        Strategy::Greedy => greedy,
+        Strategy::Exact => {
+            let bound = lower_bound(&sorted, elves);
+            let mut best = (maximum_load(&sorted, &greedy, elves), greedy);
+            let mut owners = Vec::with_capacity(sorted.len());
+            branch_and_bound(&sorted, &mut vec![0; elves], &mut owners, bound, &mut best);
+            best.1
+        }
+    };
+
+    let mut loads = vec![Vec::new(); elves];
+    for (item, owner) in sorted.into_iter().zip(owners) {
+        loads[owner].push(item);
+    }
+    Assignment { loads }
+}
+
+/// The elf each of the sorted `items` goes to, always picking the lightest elf.
+fn lpt(items: &[u32], elves: usize) -> Vec<usize> {
+    // A min-heap of (load, elf), so the lightest elf is always on top.
+    let mut heap: BinaryHeap<Reverse<(u64, usize)>> =
+        (0..elves).map(|elf| Reverse((0, elf))).collect();
+    items
+        .iter()
+        .map(|&item| {
+            let Reverse((load, elf)) = heap.pop().unwrap();
+            heap.push(Reverse((load + u64::from(item), elf)));
+            elf
+        })
+        .collect()
+}
+
+fn maximum_load(items: &[u32], owners: &[usize], elves: usize) -> u64 {
+    let mut loads = vec![0; elves];
+    for (&item, &owner) in items.iter().zip(owners) {
+        loads[owner] += u64::from(item);
+    }
+    loads.into_iter().max().unwrap_or_default()
+}
+
+/// No assignment can do better than an even split, nor than the largest item on its own.
+fn lower_bound(items: &[u32], elves: usize) -> u64 {
+    let total: u64 = items.iter().copied().map(u64::from).sum();
+    let largest = items.first().copied().map(u64::from).unwrap_or_default();
+    total.div_ceil(elves as u64).max(largest)
+}
+
+fn branch_and_bound(
+    items: &[u32],
+    loads: &mut [u64],
+    owners: &mut Vec<usize>,
+    bound: u64,
+    best: &mut (u64, Vec<usize>),
+) {
+    // Nothing can beat an assignment that already meets the lower bound.
+    if best.0 <= bound {
+        return;
+    }
+    let Some(&item) = items.get(owners.len()) else {
+        // Every branch that got this far beats the best so far.
+        best.0 = loads.iter().copied().max().unwrap_or_default();
+        best.1.clone_from(owners);
+        return;
+    };
+
+    for elf in 0..loads.len() {
+        // Elves with equal loads are interchangeable, so only the first of them is tried.
+        if loads[..elf].contains(&loads[elf]) || loads[elf] + u64::from(item) >= best.0 {
+            continue;
+        }
+        loads[elf] += u64::from(item);
+        owners.push(elf);
+        branch_and_bound(items, loads, owners, bound, best);
+        owners.pop();
+        loads[elf] -= u64::from(item);
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{solve_part1, solve_part2};
+    use super::{
+        balance, parse_elves, parse_lenient, parse_strict, report, solve_part1, solve_part2, top_k,
+        CalorieStats, Diagnostic, Problem, Strategy,
+    };
 
     const INPUT: &str = "1000\r
 2000\r
@@ -63,4 +496,121 @@ mod tests {
     fn part2() {
         assert_eq!(solve_part2(INPUT), 45000);
     }
+
+    #[test]
+    fn top_elves() {
+        assert_eq!(top_k(INPUT, 3), [(3, 24000), (2, 11000), (4, 10000)]);
+        assert_eq!(top_k(INPUT, 1), [(3, 24000)]);
+        assert_eq!(top_k(INPUT, 0), []);
+        // Asking for more elves than there are returns all of them.
+        assert_eq!(top_k(INPUT, 10).len(), 5);
+        // On a tie, the earlier elf comes first.
+        assert_eq!(top_k("1\r\n\r\n2\r\n\r\n1", 2), [(1, 2), (0, 1)]);
+    }
+
+    #[test]
+    // Every value compared here is exact.
+    #[allow(clippy::float_cmp)]
+    fn statistics() {
+        let elves = parse_elves(INPUT);
+        let stats = CalorieStats::new(&elves).unwrap();
+        assert_eq!(stats.count(), 5);
+        assert_eq!((stats.minimum(), stats.maximum()), (4000, 24000));
+        assert_eq!(stats.mean(), 11000.0);
+        assert_eq!(stats.median(), 10000.0);
+        assert_eq!(stats.percentile(25), 6000);
+        assert_eq!(stats.percentile(90), 24000);
+        assert_eq!(stats.percentile(0), 4000);
+        assert!((stats.standard_deviation() - 6985.7).abs() < 0.1);
+        assert_eq!(stats.items_per_elf(), (1, 2.0, 3));
+        assert_eq!(CalorieStats::new(&[]), None);
+    }
+
+    #[test]
+    fn calorie_report() {
+        let text = report(INPUT);
+        assert!(text.starts_with(
+            "Elves: 5
+Calories: min 4000, max 24000, mean 11000.0, median 10000.0, standard deviation 6985.7
+Percentiles: p10 4000, p25 6000, p50 10000, p75 11000, p90 24000
+Items per elf: min 1, max 3, mean 2.0
+Histogram:"
+        ));
+        assert_eq!(text.lines().count(), 5 + 10 + 1);
+        assert!(text.contains("  22000..=24000   |#################### 1\n"));
+        assert!(text.ends_with("Rebalanced: largest load 11000, imbalance 0"));
+    }
+
+    #[test]
+    fn line_endings() {
+        let unix = INPUT.replace('\r', "");
+        assert_eq!(parse_elves(&unix), parse_elves(INPUT));
+        assert_eq!(solve_part1(&unix), 24000);
+    }
+
+    #[test]
+    fn strict_parsing() {
+        let input = "1000\n12a4\n\n4294967296\n\n4294967295\n1\n";
+        let diagnostics = vec![
+            Diagnostic {
+                line: 2,
+                content: "12a4".to_owned(),
+                problem: Problem::NotANumber,
+            },
+            Diagnostic {
+                line: 4,
+                content: "4294967296".to_owned(),
+                problem: Problem::TooLarge,
+            },
+            Diagnostic {
+                line: 7,
+                content: "1".to_owned(),
+                problem: Problem::TotalOverflow,
+            },
+        ];
+        let error = parse_strict(input).unwrap_err();
+        assert_eq!(error.diagnostics, diagnostics);
+        assert_eq!(
+            error.to_string().lines().nth(1),
+            Some("line 2: `12a4` is not a number of calories")
+        );
+
+        // Lenient parsing keeps the elves, without the lines it skipped.
+        let (elves, skipped) = parse_lenient(input);
+        assert_eq!(elves, [vec![1000], vec![], vec![u32::MAX]]);
+        assert_eq!(skipped, diagnostics);
+        assert!(report(input).contains("\nSkipped 3 invalid line(s)\nline 2: `12a4`"));
+        assert!(report(input).ends_with("line 7: `1` overflows the total calories of its elf"));
+
+        // The solvers only see what lenient parsing keeps.
+        assert_eq!(solve_part1(input), u32::MAX);
+        assert_eq!(solve_part2("1000\n12a4\n\n2000\n\n3000\n\n4000"), 9000);
+    }
+
+    #[test]
+    fn load_balancing() {
+        // Every elf can end up with exactly 11000 calories.
+        let items: Vec<u32> = parse_elves(INPUT).concat();
+        for strategy in [Strategy::Greedy, Strategy::Exact] {
+            let assignment = balance(&items, 5, strategy);
+            assert_eq!(assignment.totals(), [11000; 5]);
+            assert_eq!(assignment.imbalance(), 0);
+        }
+        // Or among a different number of elves.
+        assert_eq!(balance(&items, 2, Strategy::Exact).largest_load(), 28000);
+
+        // Greedy ends up with 8 + 5 + 4 against 7 + 6, where 8 + 7 against 6 + 5 + 4 is even.
+        let items = [5, 8, 4, 7, 6];
+        let greedy = balance(&items, 2, Strategy::Greedy);
+        assert_eq!(greedy.largest_load(), 17);
+        let exact = balance(&items, 2, Strategy::Exact);
+        assert_eq!(exact.loads, [vec![8, 7], vec![6, 5, 4]]);
+        assert_eq!(
+            exact.to_string(),
+            "Elf 1: 15 (8 + 7)\nElf 2: 15 (6 + 5 + 4)\nLargest load: 15, imbalance: 0"
+        );
+
+        // More elves than items leaves some of them empty-handed.
+        assert_eq!(balance(&[3], 2, Strategy::Exact).imbalance(), 3);
+    }
 }
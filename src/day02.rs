@@ -1,21 +1,16 @@
-#[derive(Debug, Clone, Copy)]
-enum Hand {
-    Rock,
-    Paper,
-    Scissors,
-}
+use itertools::Itertools;
+
+/// A hand, as its position in the cycle of its game's hands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hand(usize);
 
 impl Hand {
-    fn points(self) -> u32 {
-        match self {
-            Hand::Rock => 1,
-            Hand::Paper => 2,
-            Hand::Scissors => 3,
-        }
+    fn points(self, game: &Game) -> u32 {
+        game.hands[self.0].points
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Outcome {
     Win,
     Draw,
@@ -32,6 +27,156 @@ impl Outcome {
     }
 }
 
+/// One of the hands of a game, and the symbols that stand for it in the strategy guide.
+#[derive(Debug, Clone, Copy)]
+struct HandRule {
+    name: &'static str,
+    points: u32,
+    opponent: char,
+    player: char,
+}
+
+/// A cyclic game of an odd number of hands,
+/// where every hand beats half of the others and loses to the other half.
+#[derive(Clone)]
+struct Game {
+    // In cyclic order: each hand beats the half of the hands just before it.
+    hands: Vec<HandRule>,
+    // The outcome for the player, indexed by the opponent's hand and then the player's.
+    table: Vec<Vec<Outcome>>,
+    // What the second column means in part 2.
+    outcomes: [(char, Outcome); 3],
+}
+
+impl Game {
+    fn cyclic(hands: Vec<HandRule>) -> Self {
+        assert!(
+            hands.len() % 2 == 1,
+            "a cyclic game needs an odd number of hands"
+        );
+        let n = hands.len();
+        let table = (0..n)
+            .map(|opponent| {
+                (0..n)
+                    .map(|player| match (player + n - opponent) % n {
+                        0 => Outcome::Draw,
+                        distance if distance <= n / 2 => Outcome::Win,
+                        _ => Outcome::Loss,
+                    })
+                    .collect()
+            })
+            .collect();
+        Game {
+            hands,
+            table,
+            outcomes: [
+                ('X', Outcome::Loss),
+                ('Y', Outcome::Draw),
+                ('Z', Outcome::Win),
+            ],
+        }
+    }
+
+    fn classic() -> Self {
+        Game::cyclic(vec![
+            HandRule {
+                name: "Rock",
+                points: 1,
+                opponent: 'A',
+                player: 'X',
+            },
+            HandRule {
+                name: "Paper",
+                points: 2,
+                opponent: 'B',
+                player: 'Y',
+            },
+            HandRule {
+                name: "Scissors",
+                points: 3,
+                opponent: 'C',
+                player: 'Z',
+            },
+        ])
+    }
+
+    /// Each hand beats two others: scissors cuts paper, paper covers rock,
+    /// rock crushes lizard, lizard poisons Spock, Spock smashes scissors,
+    /// scissors decapitates lizard, lizard eats paper, paper disproves Spock,
+    /// Spock vaporizes rock and rock crushes scissors.
+    #[cfg(test)]
+    fn rock_paper_scissors_lizard_spock() -> Self {
+        let hand = |name, points, opponent, player| HandRule {
+            name,
+            points,
+            opponent,
+            player,
+        };
+        Game::cyclic(vec![
+            hand("Rock", 1, 'A', 'V'),
+            hand("Spock", 5, 'E', 'Z'),
+            hand("Paper", 2, 'B', 'W'),
+            hand("Lizard", 4, 'D', 'Y'),
+            hand("Scissors", 3, 'C', 'X'),
+        ])
+    }
+
+    fn hand(&self, symbol: char, column: impl Fn(&HandRule) -> char) -> Hand {
+        let index = self.hands.iter().position(|hand| column(hand) == symbol);
+        Hand(index.unwrap_or_else(|| panic!("unknown hand {symbol:?}")))
+    }
+
+    fn named(&self, name: &str) -> Hand {
+        let index = self.hands.iter().position(|hand| hand.name == name);
+        Hand(index.unwrap_or_else(|| panic!("unknown hand {name:?}")))
+    }
+
+    fn outcome(&self, symbol: char) -> Outcome {
+        let outcome = self.outcomes.iter().find(|&&(other, _)| other == symbol);
+        outcome
+            .unwrap_or_else(|| panic!("unknown outcome {symbol:?}"))
+            .1
+    }
+
+    fn play(&self, opponent: Hand, player: Hand) -> Outcome {
+        self.table[opponent.0][player.0]
+    }
+
+    /// The hand that ends in `outcome` against `opponent`.
+    /// Several hands win or lose in larger games, so this picks the one worth the most.
+    fn hand_for(&self, opponent: Hand, outcome: Outcome) -> Hand {
+        (0..self.hands.len())
+            .map(Hand)
+            .filter(|&player| self.play(opponent, player) == outcome)
+            .max_by_key(|player| player.points(self))
+            .unwrap()
+    }
+
+    fn score(&self, player: Hand, outcome: Outcome) -> u32 {
+        player.points(self) + outcome.points()
+    }
+
+    fn total_by_hands(&self, input: &str) -> u32 {
+        parse(input)
+            .map(|(opponent, player)| {
+                let opponent = self.hand(opponent, |hand| hand.opponent);
+                let player = self.hand(player, |hand| hand.player);
+                self.score(player, self.play(opponent, player))
+            })
+            .sum()
+    }
+
+    fn total_by_outcomes(&self, input: &str) -> u32 {
+        parse(input)
+            .map(|(opponent, outcome)| {
+                let opponent = self.hand(opponent, |hand| hand.opponent);
+                let outcome = self.outcome(outcome);
+                self.score(self.hand_for(opponent, outcome), outcome)
+            })
+            .sum()
+    }
+}
+
 fn parse(input: &str) -> impl Iterator<Item = (char, char)> + '_ {
     input
         .lines()
@@ -40,73 +185,311 @@ fn parse(input: &str) -> impl Iterator<Item = (char, char)> + '_ {
         .map(|(left, right)| (left.chars().next().unwrap(), right.chars().next().unwrap()))
 }
 
-pub fn solve_part1(input: &str) -> u32 {
-    parse(input)
-        .map(|(opponent, player)| (convert_to_hand(opponent), convert_to_hand(player)))
-        .map(|(opponent, player)| (player, battle_outcome(opponent, player)))
-        .map(|(player, outcome)| player.points() + outcome.points())
-        .sum()
+/// What a strategy knows of its opponent, kept as running totals
+/// so that choosing a hand does not depend on the length of the match.
+struct History {
+    last: Option<Hand>,
+    counts: Vec<u32>,
 }
 
-pub fn solve_part2(input: &str) -> u32 {
-    parse(input)
-        .map(|(opponent, outcome)| (convert_to_hand(opponent), convert_to_outcome(outcome)))
-        .map(|(opponent, outcome)| (get_player_hand(opponent, outcome), outcome))
-        .map(|(player, outcome)| player.points() + outcome.points())
-        .sum()
+impl History {
+    fn new(game: &Game) -> Self {
+        History {
+            last: None,
+            counts: vec![0; game.hands.len()],
+        }
+    }
+
+    fn record(&mut self, hand: Hand) {
+        self.last = Some(hand);
+        self.counts[hand.0] += 1;
+    }
 }
 
-fn convert_to_hand(c: char) -> Hand {
-    match c {
-        'A' | 'X' => Hand::Rock,
-        'B' | 'Y' => Hand::Paper,
-        'C' | 'Z' => Hand::Scissors,
-        _ => panic!(),
+/// Chooses a hand for each round, knowing only the hands the opponent played before it.
+trait Strategy {
+    fn name(&self) -> &'static str;
+
+    fn choose(&self, game: &Game, round: usize, history: &History) -> Hand;
+}
+
+/// Plays a column of the guide, starting over when it runs out.
+struct Scripted {
+    name: &'static str,
+    hands: Vec<Hand>,
+}
+
+impl Scripted {
+    fn opponent(game: &Game, input: &str) -> Self {
+        Scripted {
+            name: "Elves",
+            hands: parse(input)
+                .map(|(opponent, _)| game.hand(opponent, |hand| hand.opponent))
+                .collect(),
+        }
+    }
+
+    fn guide(game: &Game, input: &str) -> Self {
+        Scripted {
+            name: "Guide",
+            hands: parse(input)
+                .map(|(_, player)| game.hand(player, |hand| hand.player))
+                .collect(),
+        }
     }
 }
 
-fn battle_outcome(opponent: Hand, player: Hand) -> Outcome {
-    match (opponent, player) {
-        (Hand::Rock, Hand::Paper)
-        | (Hand::Paper, Hand::Scissors)
-        | (Hand::Scissors, Hand::Rock) => Outcome::Win,
-        (Hand::Rock, Hand::Scissors)
-        | (Hand::Paper, Hand::Rock)
-        | (Hand::Scissors, Hand::Paper) => Outcome::Loss,
-        (Hand::Paper, Hand::Paper)
-        | (Hand::Rock, Hand::Rock)
-        | (Hand::Scissors, Hand::Scissors) => Outcome::Draw,
+impl Strategy for Scripted {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn choose(&self, _: &Game, round: usize, _: &History) -> Hand {
+        self.hands[round % self.hands.len()]
+    }
+}
+
+struct AlwaysRock;
+
+impl Strategy for AlwaysRock {
+    fn name(&self) -> &'static str {
+        "Always rock"
+    }
+
+    fn choose(&self, game: &Game, _: usize, _: &History) -> Hand {
+        game.named("Rock")
     }
 }
 
-fn convert_to_outcome(c: char) -> Outcome {
-    match c {
-        'X' => Outcome::Loss,
-        'Y' => Outcome::Draw,
-        'Z' => Outcome::Win,
-        _ => panic!(),
+/// Plays whatever the opponent played last.
+struct Mirror;
+
+impl Strategy for Mirror {
+    fn name(&self) -> &'static str {
+        "Mirror"
+    }
+
+    fn choose(&self, _: &Game, _: usize, history: &History) -> Hand {
+        history.last.unwrap_or(Hand(0))
     }
 }
 
-fn get_player_hand(opponent: Hand, outcome: Outcome) -> Hand {
-    match outcome {
-        Outcome::Win => match opponent {
-            Hand::Rock => Hand::Paper,
-            Hand::Paper => Hand::Scissors,
-            Hand::Scissors => Hand::Rock,
-        },
-        Outcome::Draw => opponent,
-        Outcome::Loss => match opponent {
-            Hand::Rock => Hand::Scissors,
-            Hand::Paper => Hand::Rock,
-            Hand::Scissors => Hand::Paper,
-        },
+/// Beats the hand the opponent played most often so far.
+struct FrequencyCounter;
+
+impl Strategy for FrequencyCounter {
+    fn name(&self) -> &'static str {
+        "Frequency counter"
     }
+
+    fn choose(&self, game: &Game, _: usize, history: &History) -> Hand {
+        // The first hand wins ties, so that an empty history is expected to be the first hand.
+        let favourite = (0..history.counts.len())
+            .rev()
+            .max_by_key(|&hand| history.counts[hand])
+            .unwrap();
+        game.hand_for(Hand(favourite), Outcome::Win)
+    }
+}
+
+/// Picks a hand at random, the same one for the same seed and round.
+struct Random {
+    seed: u64,
+}
+
+impl Strategy for Random {
+    fn name(&self) -> &'static str {
+        "Random"
+    }
+
+    fn choose(&self, game: &Game, round: usize, _: &History) -> Hand {
+        // SplitMix64, which scrambles consecutive inputs well enough to use the round as a counter.
+        let mut z = self
+            .seed
+            .wrapping_add((round as u64 + 1).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        Hand(usize::try_from(z % game.hands.len() as u64).unwrap())
+    }
+}
+
+/// How a strategy fared over the matches it played.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Record {
+    score: u32,
+    wins: u32,
+    draws: u32,
+    losses: u32,
+}
+
+impl Record {
+    fn add(&mut self, game: &Game, hand: Hand, outcome: Outcome) {
+        self.score += game.score(hand, outcome);
+        match outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Loss => self.losses += 1,
+        }
+    }
+
+    fn rounds(self) -> u32 {
+        self.wins + self.draws + self.losses
+    }
+
+    fn rate(self, count: u32) -> f64 {
+        f64::from(count) / f64::from(self.rounds().max(1))
+    }
+
+    /// The points to expect from a single round.
+    fn expected_value(self) -> f64 {
+        self.rate(self.score)
+    }
+}
+
+fn play_match(
+    game: &Game,
+    left: &dyn Strategy,
+    right: &dyn Strategy,
+    rounds: usize,
+) -> (Record, Record) {
+    let (mut left_hands, mut right_hands) = (History::new(game), History::new(game));
+    let (mut left_record, mut right_record) = (Record::default(), Record::default());
+    for round in 0..rounds {
+        let left_hand = left.choose(game, round, &right_hands);
+        let right_hand = right.choose(game, round, &left_hands);
+        left_record.add(game, left_hand, game.play(right_hand, left_hand));
+        right_record.add(game, right_hand, game.play(left_hand, right_hand));
+        left_hands.record(left_hand);
+        right_hands.record(right_hand);
+    }
+    (left_record, right_record)
+}
+
+/// Plays every strategy against every other one, returning their records in the same order.
+fn tournament(game: &Game, strategies: &[Box<dyn Strategy>], rounds: usize) -> Vec<Record> {
+    let mut records = vec![Record::default(); strategies.len()];
+    for (left, right) in (0..strategies.len()).tuple_combinations() {
+        let (left_record, right_record) =
+            play_match(game, &*strategies[left], &*strategies[right], rounds);
+        for (record, result) in [(left, left_record), (right, right_record)] {
+            let record = &mut records[record];
+            record.score += result.score;
+            record.wins += result.wins;
+            record.draws += result.draws;
+            record.losses += result.losses;
+        }
+    }
+    records
+}
+
+fn standings(strategies: &[Box<dyn Strategy>], records: &[Record]) -> Vec<String> {
+    let mut lines = vec![format!(
+        "  {:<18}{:>8}{:>8}{:>8}{:>8}{:>8}",
+        "Strategy", "Score", "Win", "Draw", "Loss", "EV"
+    )];
+    for (strategy, &record) in strategies.iter().zip(records) {
+        lines.push(format!(
+            "  {:<18}{:>8}{:>7.1}%{:>7.1}%{:>7.1}%{:>8.2}",
+            strategy.name(),
+            record.score,
+            record.rate(record.wins) * 100.0,
+            record.rate(record.draws) * 100.0,
+            record.rate(record.losses) * 100.0,
+            record.expected_value(),
+        ));
+    }
+    lines
+}
+
+pub fn solve_part1(input: &str) -> u32 {
+    Game::classic().total_by_hands(input)
+}
+
+pub fn solve_part2(input: &str) -> u32 {
+    Game::classic().total_by_outcomes(input)
+}
+
+/// The scores of both parts for every round of the classic game,
+/// indexed by the opponent's symbol and then the second column.
+const SCORES: [[(u32, u32); 3]; 3] = [
+    [(4, 3), (8, 4), (3, 8)],
+    [(1, 1), (5, 5), (9, 9)],
+    [(7, 2), (2, 6), (6, 7)],
+];
+
+/// Both answers in a single pass over the bytes of a guide in exactly the `"A X\n"` layout,
+/// for guides too large for the general parser.
+/// Any other layout, such as `"\r\n"` line endings, is left to the general parser.
+pub fn solve_fast(input: &str) -> (u32, u32) {
+    let rounds = input.trim_end().as_bytes();
+    // Every round takes four bytes, except for the last one without its newline.
+    let fast = (rounds.len() % 4 == 3).then(|| {
+        rounds.chunks(4).try_fold((0, 0), |(part1, part2), round| {
+            let [opponent @ b'A'..=b'C', b' ', column @ b'X'..=b'Z', ..] = *round else {
+                return None;
+            };
+            if round.len() == 4 && round[3] != b'\n' {
+                return None;
+            }
+            let (first, second) = SCORES[usize::from(opponent - b'A')][usize::from(column - b'X')];
+            Some((part1 + first, part2 + second))
+        })
+    });
+    fast.flatten()
+        .unwrap_or_else(|| (solve_part1(input), solve_part2(input)))
+}
+
+/// Scores the guide under every way of reading its second column as hands,
+/// and then as the outcomes of part 2, to show how much the decoding matters.
+pub fn report(input: &str) -> String {
+    let game = Game::classic();
+    let symbols = game.hands.iter().map(|hand| hand.player).collect_vec();
+    let mut lines = vec![format!("Reading {} as:", symbols.iter().join(" "))];
+    for hands in (0..symbols.len()).permutations(symbols.len()) {
+        let mut decoded = game.clone();
+        for (&symbol, &hand) in symbols.iter().zip(&hands) {
+            decoded.hands[hand].player = symbol;
+        }
+        let names = hands.iter().map(|&hand| game.hands[hand].name).join(" ");
+        lines.push(format!("  {names}: {}", decoded.total_by_hands(input)));
+    }
+    let outcomes = game.outcomes.map(|(_, outcome)| outcome);
+    for outcomes in outcomes.iter().permutations(outcomes.len()) {
+        let mut decoded = game.clone();
+        for ((_, slot), &&outcome) in decoded.outcomes.iter_mut().zip(&outcomes) {
+            *slot = outcome;
+        }
+        let names = outcomes
+            .iter()
+            .map(|outcome| format!("{outcome:?}"))
+            .join(" ");
+        lines.push(format!("  {names}: {}", decoded.total_by_outcomes(input)));
+    }
+
+    let rounds = parse(input).count();
+    let strategies: Vec<Box<dyn Strategy>> = vec![
+        Box::new(Scripted::opponent(&game, input)),
+        Box::new(Scripted::guide(&game, input)),
+        Box::new(AlwaysRock),
+        Box::new(Mirror),
+        Box::new(FrequencyCounter),
+        Box::new(Random { seed: 2022 }),
+    ];
+    lines.push(format!("Round-robin of {rounds} rounds per match:"));
+    lines.extend(standings(
+        &strategies,
+        &tournament(&game, &strategies, rounds),
+    ));
+    lines.join("\n")
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day02::{solve_part1, solve_part2};
+    use crate::day02::{
+        play_match, report, solve_fast, solve_part1, solve_part2, tournament, AlwaysRock,
+        FrequencyCounter, Game, Hand, History, Mirror, Outcome, Random, Scripted, Strategy,
+    };
 
     const INPUT: &str = "A Y
 B X
@@ -121,4 +504,138 @@ C Z";
     fn part2() {
         assert_eq!(solve_part2(INPUT), 12);
     }
+
+    #[test]
+    fn lookup_table() {
+        assert_eq!(solve_fast(INPUT), (15, 12));
+        assert_eq!(solve_fast(&format!("{INPUT}\n")), (15, 12));
+        // Other layouts are still solved, by the general parser.
+        assert_eq!(solve_fast(&INPUT.replace('\n', "\r\n")), (15, 12));
+        assert_eq!(solve_fast(&format!("{INPUT}\n\n")), (15, 12));
+        assert_eq!(solve_fast(""), (0, 0));
+
+        let game = Game::classic();
+        for opponent in ['A', 'B', 'C'] {
+            for second in ['X', 'Y', 'Z'] {
+                let round = format!("{opponent} {second}");
+                let expected = (game.total_by_hands(&round), game.total_by_outcomes(&round));
+                assert_eq!(solve_fast(&round), expected, "{round}");
+            }
+        }
+    }
+
+    #[test]
+    fn lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let hand = |symbol| game.hand(symbol, |hand| hand.opponent);
+        for (winner, loser) in [
+            ('C', 'B'), // Scissors cuts paper.
+            ('B', 'A'), // Paper covers rock.
+            ('A', 'D'), // Rock crushes lizard.
+            ('D', 'E'), // Lizard poisons Spock.
+            ('E', 'C'), // Spock smashes scissors.
+            ('C', 'D'), // Scissors decapitates lizard.
+            ('D', 'B'), // Lizard eats paper.
+            ('B', 'E'), // Paper disproves Spock.
+            ('E', 'A'), // Spock vaporizes rock.
+            ('A', 'C'), // Rock crushes scissors.
+        ] {
+            assert_eq!(game.play(hand(loser), hand(winner)), Outcome::Win);
+            assert_eq!(game.play(hand(winner), hand(loser)), Outcome::Loss);
+        }
+
+        // Rock against Spock loses.
+        assert_eq!(game.total_by_hands("E V"), 1);
+        // Both Paper and Spock beat Rock, Spock is worth more. Then a draw with Lizard.
+        assert_eq!(game.total_by_outcomes("A Z\nD Y"), 5 + 6 + 4 + 3);
+    }
+
+    #[test]
+    fn analysis() {
+        assert_eq!(
+            report(INPUT),
+            "Reading X Y Z as:
+  Rock Paper Scissors: 15
+  Rock Scissors Paper: 6
+  Paper Rock Scissors: 15
+  Paper Scissors Rock: 15
+  Scissors Rock Paper: 15
+  Scissors Paper Rock: 24
+  Loss Draw Win: 12
+  Loss Win Draw: 15
+  Draw Loss Win: 15
+  Draw Win Loss: 15
+  Win Loss Draw: 18
+  Win Draw Loss: 15
+Round-robin of 3 rounds per match:
+  Strategy             Score     Win    Draw    Loss      EV
+  Elves                   81   40.0%   33.3%   26.7%    5.40
+  Guide                   78   40.0%   26.7%   33.3%    5.20
+  Always rock             42   13.3%   33.3%   53.3%    2.80
+  Mirror                  57   13.3%   53.3%   33.3%    3.80
+  Frequency counter       90   46.7%   33.3%   20.0%    6.00
+  Random                  81   40.0%   33.3%   26.7%    5.40"
+        );
+    }
+
+    #[test]
+    fn matches() {
+        let game = Game::classic();
+        let guide = Scripted::guide(&game, INPUT);
+        let elves = Scripted::opponent(&game, INPUT);
+        let (guide_record, _) = play_match(&game, &guide, &elves, 3);
+        assert_eq!(guide_record.score, solve_part1(INPUT));
+        assert_eq!(
+            (guide_record.wins, guide_record.draws, guide_record.losses),
+            (1, 1, 1)
+        );
+        assert!((guide_record.expected_value() - 5.0).abs() < 1e-9);
+
+        // Mirror starts with Rock, then copies the Rock, Paper, Scissors of the elves a round late.
+        let (mirror, _) = play_match(&game, &Mirror, &elves, 4);
+        assert_eq!((mirror.wins, mirror.draws, mirror.losses), (0, 1, 3));
+
+        // Expecting Rock before it has seen anything, the frequency counter plays Paper throughout.
+        let (counter, rock) = play_match(&game, &FrequencyCounter, &AlwaysRock, 3);
+        assert_eq!((counter.wins, counter.draws, counter.losses), (3, 0, 0));
+        assert_eq!(rock.score, 3);
+        // Against a guide that only plays Paper, it catches on after the first round.
+        let (counter, _) = play_match(
+            &game,
+            &FrequencyCounter,
+            &Scripted::guide(&game, "A Y\nA Y\nA Y"),
+            3,
+        );
+        assert_eq!((counter.wins, counter.draws, counter.losses), (2, 1, 0));
+
+        let hands = |seed| {
+            let random = Random { seed };
+            let history = History::new(&game);
+            (0..100)
+                .map(|round| random.choose(&game, round, &history))
+                .collect::<Vec<Hand>>()
+        };
+        assert_eq!(hands(7), hands(7));
+        assert_ne!(hands(7), hands(8));
+        let hands = hands(7);
+        assert!((0..3).all(|hand| hands.contains(&Hand(hand))));
+    }
+
+    #[test]
+    fn round_robin() {
+        let game = Game::classic();
+        let strategies: Vec<Box<dyn Strategy>> = vec![
+            Box::new(Scripted::guide(&game, INPUT)),
+            Box::new(AlwaysRock),
+            Box::new(Mirror),
+            Box::new(Random { seed: 1 }),
+        ];
+        let records = tournament(&game, &strategies, 10);
+        for record in &records {
+            assert_eq!(record.rounds(), 30);
+        }
+        let wins: u32 = records.iter().map(|record| record.wins).sum();
+        let losses: u32 = records.iter().map(|record| record.losses).sum();
+        assert_eq!(wins, losses);
+    }
 }
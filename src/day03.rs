@@ -1,43 +1,47 @@
-use std::collections::HashSet;
-
 use itertools::Itertools;
 
+/// A set of items, as a bitmask with each item at the bit of its priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct ItemSet(u64);
+
+impl ItemSet {
+    fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    fn priority_sum(self) -> u32 {
+        let mut items = self.0;
+        let mut sum = 0;
+        while items != 0 {
+            sum += items.trailing_zeros();
+            items &= items - 1;
+        }
+        sum
+    }
+}
+
+impl From<&str> for ItemSet {
+    fn from(items: &str) -> Self {
+        ItemSet(items.chars().fold(0, |set, item| set | 1 << priority(item)))
+    }
+}
+
 pub fn solve_part1(input: &str) -> u32 {
     input
         .lines()
         .map(|rucksack| rucksack.split_at(rucksack.len() / 2))
-        .map(|(comp1, comp2)| {
-            (
-                comp1.chars().collect::<HashSet<char>>(),
-                comp2.chars().collect::<HashSet<char>>(),
-            )
-        })
-        .map(|(comp1, comp2)| comp1.intersection(&comp2).copied().collect::<Vec<_>>())
-        .map(|common| common.into_iter().map(priority).sum::<u32>())
+        .map(|(comp1, comp2)| ItemSet::from(comp1).intersection(ItemSet::from(comp2)))
+        .map(ItemSet::priority_sum)
         .sum()
 }
 
 pub fn solve_part2(input: &str) -> u32 {
     input
         .lines()
-        .chunks(3)
-        .into_iter()
-        .map(|mut chunk| {
-            (
-                chunk.next().unwrap().chars().collect::<HashSet<char>>(),
-                chunk.next().unwrap().chars().collect::<HashSet<char>>(),
-                chunk.next().unwrap().chars().collect::<HashSet<char>>(),
-            )
-        })
-        .map(|(rs1, rs2, rs3)| {
-            rs1.intersection(&rs2)
-                .copied()
-                .collect::<HashSet<char>>()
-                .intersection(&rs3)
-                .copied()
-                .collect_vec()
-        })
-        .map(|badge| badge.into_iter().map(priority).sum::<u32>())
+        .map(ItemSet::from)
+        .tuples()
+        .map(|(rs1, rs2, rs3)| rs1.intersection(rs2).intersection(rs3))
+        .map(ItemSet::priority_sum)
         .sum()
 }
 
@@ -51,7 +55,7 @@ fn priority(c: char) -> u32 {
 
 #[cfg(test)]
 mod tests {
-    use crate::day03::{priority, solve_part1, solve_part2};
+    use crate::day03::{priority, solve_part1, solve_part2, ItemSet};
 
     const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
 jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
@@ -71,6 +75,15 @@ CrZsJsPPZsGzwwsLwLmpwMDw";
         }
     }
 
+    #[test]
+    fn item_sets() {
+        let first = ItemSet::from("vJrwpWtwJgWrhcsFMMfFFhFp");
+        let second = ItemSet::from("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL");
+        assert_eq!(first.intersection(second), ItemSet::from("rsfFM"));
+        assert_eq!(ItemSet::from("aaZ").priority_sum(), 1 + 52);
+        assert_eq!(ItemSet::default().priority_sum(), 0);
+    }
+
     #[test]
     fn part1() {
         assert_eq!(solve_part1(INPUT), 157);
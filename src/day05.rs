@@ -1,8 +1,11 @@
 use itertools::Itertools;
 
+use crate::simulation::Simulation;
+
 #[derive(Debug, Clone)]
 struct Stack(Vec<char>);
 
+#[derive(Debug, Clone)]
 pub struct Stacks(Vec<Stack>);
 
 impl Stacks {
@@ -38,6 +41,51 @@ struct Step {
 
 pub struct Steps(Vec<Step>);
 
+/// Performs the rearrangement one step at a time.
+/// With `MOVE_MULTIPLE`, the crates of a step are moved at once and keep their order.
+struct Crane<'a, const MOVE_MULTIPLE: bool> {
+    stacks: Stacks,
+    steps: &'a [Step],
+    next_step: usize,
+}
+
+impl<'a, const MOVE_MULTIPLE: bool> Crane<'a, MOVE_MULTIPLE> {
+    fn new(stacks: Stacks, steps: &'a Steps) -> Self {
+        Self {
+            stacks,
+            steps: &steps.0,
+            next_step: 0,
+        }
+    }
+}
+
+impl<const MOVE_MULTIPLE: bool> Simulation for Crane<'_, MOVE_MULTIPLE> {
+    type Snapshot = (Stacks, usize);
+
+    fn step(&mut self) {
+        let step = &self.steps[self.next_step];
+        if MOVE_MULTIPLE {
+            self.stacks.perform_step_part2(step);
+        } else {
+            self.stacks.perform_step_part1(step);
+        }
+        self.next_step += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.next_step == self.steps.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.stacks.clone(), self.next_step)
+    }
+
+    fn restore(&mut self, (stacks, next_step): Self::Snapshot) {
+        self.stacks = stacks;
+        self.next_step = next_step;
+    }
+}
+
 pub fn parse(input: &str) -> (Stacks, Steps) {
     let input = input.replace("\r\n", "\n");
 
@@ -78,28 +126,25 @@ pub fn parse(input: &str) -> (Stacks, Steps) {
 }
 
 pub fn solve_part1(input: &str) -> String {
-    let (mut stacks, steps) = parse(input);
-
-    for step in &steps.0 {
-        stacks.perform_step_part1(step);
-    }
-
-    stacks.top_crates()
+    let (stacks, steps) = parse(input);
+    let mut crane = Crane::<false>::new(stacks, &steps);
+    crane.run_to_end();
+    crane.stacks.top_crates()
 }
 
 pub fn solve_part2(input: &str) -> String {
-    let (mut stacks, steps) = parse(input);
-
-    for step in &steps.0 {
-        stacks.perform_step_part2(step);
-    }
-
-    stacks.top_crates()
+    let (stacks, steps) = parse(input);
+    let mut crane = Crane::<true>::new(stacks, &steps);
+    crane.run_to_end();
+    crane.stacks.top_crates()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day05::{solve_part1, solve_part2};
+    use crate::{
+        day05::{parse, solve_part1, solve_part2, Crane},
+        simulation::Recorder,
+    };
 
     const INPUT: &str = "    [D]    
 [N] [C]    
@@ -120,4 +165,17 @@ move 1 from 1 to 2";
     fn part2() {
         assert_eq!(solve_part2(INPUT), "MCD");
     }
+
+    #[test]
+    fn rewind() {
+        let (stacks, steps) = parse(INPUT);
+        let mut recorder = Recorder::new(Crane::<false>::new(stacks, &steps), 4);
+        recorder.run_to_end();
+        assert_eq!(recorder.simulation().stacks.top_crates(), "CMZ");
+        assert_eq!(recorder.get(1).unwrap().0.top_crates(), "DCP");
+        assert!(recorder.rewind_to(1));
+        assert_eq!(recorder.simulation().stacks.top_crates(), "DCP");
+        recorder.run_to_end();
+        assert_eq!(recorder.simulation().stacks.top_crates(), "CMZ");
+    }
 }
@@ -2,6 +2,8 @@ use std::{fmt, str::FromStr};
 
 use indextree::{Arena, Node, NodeEdge, NodeId};
 
+use crate::instrument;
+
 pub(crate) struct Folder {
     name: String,
     id: Option<NodeId>,
@@ -28,6 +30,7 @@ impl fmt::Debug for Directory {
 
 impl Directory {
     fn size(&self, file_system: &FileSystem) -> u32 {
+        instrument::count("Directory::size calls");
         match self {
             Directory::Folder(Folder { id, .. }) => id
                 .unwrap()
@@ -163,7 +166,7 @@ pub fn solve_part1(input: &str) -> u32 {
     parse(input)
         .sizes()
         .into_iter()
-        .filter_map(|size| (size < 100_000).then_some(size))
+        .filter(|&size| size < 100_000)
         .sum()
 }
 
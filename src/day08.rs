@@ -58,16 +58,13 @@ impl Grid {
     }
 
     fn is_visible(&self, x: usize, y: usize) -> bool {
-        (Direction::DIRECTIONS)
-            .into_iter()
-            .map(|direction| {
-                let (tree, blocking_trees) = self.trees(x, y, direction);
-                // all blocking trees must be less than the tree for it to be visible
-                blocking_trees
-                    .into_iter()
-                    .all(|blocking_tree| tree > blocking_tree)
-            })
-            .any(|x| x)
+        (Direction::DIRECTIONS).into_iter().any(|direction| {
+            let (tree, blocking_trees) = self.trees(x, y, direction);
+            // all blocking trees must be less than the tree for it to be visible
+            blocking_trees
+                .into_iter()
+                .all(|blocking_tree| tree > blocking_tree)
+        })
     }
 
     fn scenic_score(&self, x: usize, y: usize) -> usize {
@@ -1,5 +1,7 @@
 use std::{collections::HashSet, fmt, ops::Sub};
 
+use crate::simulation::Simulation;
+
 #[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
 struct Coordinate {
     x: isize,
@@ -27,8 +29,8 @@ impl Coordinate {
     fn is_adjacent_with(self, other: Coordinate) -> bool {
         match self - other {
             Coordinate {
-                x: 0 | 1 | -1,
-                y: 0 | 1 | -1,
+                x: -1..=1,
+                y: -1..=1,
             } => true,
             Coordinate { .. } => false,
         }
@@ -40,37 +42,34 @@ struct Interpreter<const N: usize> {
     dirty: HashSet<Coordinate>,
     head: Coordinate,
     tail: [Coordinate; N],
+    // Each command is split into moves of a single step.
+    moves: Vec<Direction>,
+    next_move: usize,
 }
 
 impl<const N: usize> Interpreter<N> {
-    fn new() -> Self {
-        Self {
+    fn new(commands: Vec<Command>) -> Self {
+        let mut interpreter = Self {
             dirty: HashSet::new(),
             head: Coordinate::default(),
             tail: [Coordinate::default(); N],
-        }
+            moves: commands
+                .into_iter()
+                .flat_map(|Command(direction, distance)| {
+                    std::iter::repeat_n(direction, distance.into())
+                })
+                .collect(),
+            next_move: 0,
+        };
+        interpreter.dirty();
+        interpreter
     }
 
-    fn run(&mut self, commands: Vec<Command>) -> usize {
-        self.dirty();
-        self.interpret_commands(commands);
+    fn run(&mut self) -> usize {
+        self.run_to_end();
         self.dirty.len()
     }
 
-    fn interpret_commands(&mut self, commands: Vec<Command>) {
-        for command in commands {
-            self.interpret_command(command);
-        }
-    }
-
-    fn interpret_command(&mut self, Command(direction, distance): Command) {
-        for _ in 0..distance {
-            self.move_head(direction);
-            self.move_tail();
-            self.dirty();
-        }
-    }
-
     fn move_head(&mut self, direction: Direction) {
         match direction {
             Direction::Right => self.head.x += 1,
@@ -110,7 +109,9 @@ impl<const N: usize> Interpreter<N> {
         move_tail_inner(&mut self.head, &mut self.tail[0]);
 
         for n in 0..self.tail.len() - 1 {
-            let [head, tail, ..] = &mut self.tail[n..] else { unreachable!() };
+            let [head, tail, ..] = &mut self.tail[n..] else {
+                unreachable!()
+            };
             move_tail_inner(head, tail);
         }
     }
@@ -120,6 +121,32 @@ impl<const N: usize> Interpreter<N> {
     }
 }
 
+impl<const N: usize> Simulation for Interpreter<N> {
+    type Snapshot = (HashSet<Coordinate>, Coordinate, [Coordinate; N], usize);
+
+    fn step(&mut self) {
+        self.move_head(self.moves[self.next_move]);
+        self.move_tail();
+        self.dirty();
+        self.next_move += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.next_move == self.moves.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.dirty.clone(), self.head, self.tail, self.next_move)
+    }
+
+    fn restore(&mut self, (dirty, head, tail, next_move): Self::Snapshot) {
+        self.dirty = dirty;
+        self.head = head;
+        self.tail = tail;
+        self.next_move = next_move;
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Direction {
     Right,
@@ -151,16 +178,19 @@ fn parse(input: &str) -> Vec<Command> {
 }
 
 pub fn solve_part1(input: &str) -> usize {
-    Interpreter::<1>::new().run(parse(input))
+    Interpreter::<1>::new(parse(input)).run()
 }
 
 pub fn solve_part2(input: &str) -> usize {
-    Interpreter::<9>::new().run(parse(input))
+    Interpreter::<9>::new(parse(input)).run()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day09::{solve_part1, solve_part2};
+    use crate::{
+        day09::{parse, solve_part1, solve_part2, Coordinate, Interpreter},
+        simulation::Recorder,
+    };
 
     const INPUT: &str = "R 4
 U 4
@@ -190,4 +220,19 @@ U 20";
         assert_eq!(solve_part2(INPUT), 1);
         assert_eq!(solve_part2(INPUT2), 36);
     }
+
+    #[test]
+    fn rewind() {
+        let mut recorder = Recorder::new(Interpreter::<1>::new(parse(INPUT)), 24);
+        recorder.run_to_end();
+        assert_eq!(recorder.steps(), 24);
+        // After "R 4", the head is 4 steps right and the tail is right behind it.
+        let (dirty, head, tail, _) = recorder.get(4).unwrap();
+        assert_eq!(*head, Coordinate { x: 4, y: 0 });
+        assert_eq!(tail[0], Coordinate { x: 3, y: 0 });
+        assert_eq!(dirty.len(), 4);
+        assert!(recorder.rewind_to(4));
+        assert_eq!(recorder.simulation().head, Coordinate { x: 4, y: 0 });
+        assert_eq!(recorder.into_inner().run(), 13);
+    }
 }
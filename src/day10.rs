@@ -1,10 +1,9 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    fmt,
-};
+use std::{collections::HashMap, fmt};
 
 use itertools::Itertools;
 
+use crate::simulation::Simulation;
+
 #[derive(Debug, Clone, Copy)]
 enum Command {
     NoOp,
@@ -27,7 +26,8 @@ fn parse(input: &str) -> Vec<Command> {
 
 struct Clock {
     cycle: u32,
-    commands: VecDeque<Command>,
+    // Every command here takes exactly one cycle.
+    commands: Vec<Command>,
     register: i32,
 }
 
@@ -35,7 +35,7 @@ impl fmt::Debug for Clock {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Clock")
             .field("cycle", &self.cycle)
-            .field("next_command", &self.commands.iter().next())
+            .field("next_command", &self.commands.get(self.next_command()))
             .field("register", &self.register)
             .finish()
     }
@@ -56,17 +56,15 @@ impl Clock {
         }
     }
 
+    fn next_command(&self) -> usize {
+        (self.cycle - 1) as usize
+    }
+
     fn run(&mut self) -> HashMap<u32, i32> {
         let mut memorized_cycles = HashMap::new();
-        for command in &self.commands {
+        while !self.is_done() {
             memorized_cycles.insert(self.cycle, self.register);
-
-            match command {
-                Command::NoOp => {}
-                Command::AddX(x) => self.register += x,
-            }
-
-            self.cycle += 1;
+            self.step();
         }
 
         memorized_cycles
@@ -81,6 +79,32 @@ impl Clock {
     }
 }
 
+impl Simulation for Clock {
+    type Snapshot = (u32, i32);
+
+    fn step(&mut self) {
+        match self.commands[self.next_command()] {
+            Command::NoOp => {}
+            Command::AddX(x) => self.register += x,
+        }
+
+        self.cycle += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.next_command() == self.commands.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.cycle, self.register)
+    }
+
+    fn restore(&mut self, (cycle, register): Self::Snapshot) {
+        self.cycle = cycle;
+        self.register = register;
+    }
+}
+
 pub fn solve_part1(input: &str) -> i32 {
     Clock::new(parse(input)).get_product_cycles()
 }
@@ -124,7 +148,10 @@ mod tests {
 
     use pretty_assertions::{assert_eq, assert_str_eq};
 
-    use crate::day10::{solve_part1, solve_part2};
+    use crate::{
+        day10::{solve_part1, solve_part2},
+        simulation::{Recorder, Simulation},
+    };
 
     use super::{parse, Clock};
 
@@ -299,12 +326,7 @@ noop";
             .run()
             .into_iter()
             .filter(|(cycle, _)| matches!(cycle, 20 | 60 | 100 | 140 | 180 | 220))
-            .map(|(cycle, register)| {
-                (
-                    cycle,
-                    TryInto::<i32>::try_into(cycle).unwrap() * register as i32,
-                )
-            })
+            .map(|(cycle, register)| (cycle, TryInto::<i32>::try_into(cycle).unwrap() * register))
             .sorted()
             .collect();
         assert_eq!(clock, {
@@ -350,4 +372,15 @@ noop";
         // println!("{}", solve_part2(INPUT));
         assert_str_eq!(solve_part2(INPUT), ANSWER_PART2);
     }
+
+    #[test]
+    fn rewind() {
+        let mut recorder = Recorder::new(Clock::new(parse("noop\naddx 3\naddx -5")), 5);
+        recorder.run_to_end();
+        assert!(recorder.simulation().is_done());
+        assert_eq!(recorder.simulation().register, -1);
+        assert_eq!(recorder.get(3), Some(&(4, 4)));
+        assert!(recorder.rewind_to(3));
+        assert_eq!(recorder.into_inner().run(), HashMap::from([(4, 4), (5, 4)]));
+    }
 }
@@ -6,6 +6,8 @@ use std::{
 
 use itertools::Itertools;
 
+use crate::{math::lcm, operator::Operator, simulation::Simulation};
+
 #[derive(Debug, Clone, Copy)]
 struct Item {
     worry_level: u64,
@@ -57,6 +59,7 @@ impl From<u64> for Item {
     }
 }
 
+#[derive(Debug, Clone)]
 struct Items(VecDeque<Item>);
 
 #[derive(Debug, Clone, Copy)]
@@ -78,24 +81,8 @@ struct Operation {
 
 impl Operation {
     fn operate(&self, old: Item) -> Item {
-        match (self.left, self.operator, self.right) {
-            (Operand::Variable, Operator::Add, Operand::Variable) => old + old,
-            (Operand::Variable, Operator::Add, Operand::Number(y)) => old + y.into(),
-            (Operand::Variable, Operator::Sub, Operand::Variable) => 0.into(),
-            (Operand::Variable, Operator::Sub, Operand::Number(y)) => old - y.into(),
-            (Operand::Variable, Operator::Mul, Operand::Variable) => old * old,
-            (Operand::Variable, Operator::Mul, Operand::Number(y)) => old * y.into(),
-            (Operand::Variable, Operator::Div, Operand::Variable) => 1.into(),
-            (Operand::Variable, Operator::Div, Operand::Number(y)) => old / y.into(),
-            (Operand::Number(x), Operator::Add, Operand::Variable) => Item::from(x) + old,
-            (Operand::Number(x), Operator::Add, Operand::Number(y)) => Item::from(x) + y.into(),
-            (Operand::Number(x), Operator::Sub, Operand::Variable) => Item::from(x) - old,
-            (Operand::Number(x), Operator::Sub, Operand::Number(y)) => Item::from(x) - y.into(),
-            (Operand::Number(x), Operator::Mul, Operand::Variable) => Item::from(x) * old,
-            (Operand::Number(x), Operator::Mul, Operand::Number(y)) => Item::from(x) * y.into(),
-            (Operand::Number(x), Operator::Div, Operand::Variable) => Item::from(x) / old,
-            (Operand::Number(x), Operator::Div, Operand::Number(y)) => Item::from(x) / y.into(),
-        }
+        self.operator
+            .apply(self.left.value(old), self.right.value(old))
     }
 }
 
@@ -105,12 +92,13 @@ enum Operand {
     Number(u64),
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Operator {
-    Add,
-    Sub,
-    Mul,
-    Div,
+impl Operand {
+    fn value(self, old: Item) -> Item {
+        match self {
+            Operand::Variable => old,
+            Operand::Number(number) => number.into(),
+        }
+    }
 }
 
 struct Test {
@@ -121,7 +109,7 @@ struct Test {
 
 impl Test {
     fn test(&self, item: Item) -> Index {
-        if item.worry_level % self.divisibility_factor == 0 {
+        if item.worry_level.is_multiple_of(self.divisibility_factor) {
             self.is_true
         } else {
             self.is_false
@@ -195,13 +183,7 @@ impl FromStr for Monkey {
                 .collect_tuple()
                 .unwrap();
             let left: Operand = left.parse().unwrap();
-            let operator = match operator {
-                "+" => Operator::Add,
-                "-" => Operator::Sub,
-                "*" => Operator::Mul,
-                "/" => Operator::Div,
-                _ => unreachable!(),
-            };
+            let operator: Operator = operator.parse().unwrap();
             let right: Operand = right.parse().unwrap();
             Operation {
                 left,
@@ -283,64 +265,94 @@ fn throw_items(monkeys: &mut [Monkey], items: Vec<(Index, Item)>) {
     }
 }
 
-pub fn solve_part1(input: &str) -> usize {
-    let mut monkeys = parse(input);
-    for _ in 0..20 {
-        for index in 0..monkeys.len() {
-            let to_throw = monkeys[index].inspect::<true>(u64::MAX);
-            throw_items(&mut monkeys, to_throw);
+/// The monkeys playing keep away, a whole round per step.
+struct KeepAway<const DIV3: bool> {
+    monkeys: Vec<Monkey>,
+    divisor: u64,
+    round: usize,
+    rounds: usize,
+}
+
+impl<const DIV3: bool> KeepAway<DIV3> {
+    fn new(monkeys: Vec<Monkey>, divisor: u64, rounds: usize) -> Self {
+        Self {
+            monkeys,
+            divisor,
+            round: 0,
+            rounds,
         }
     }
-    monkeys
-        .into_iter()
-        .sorted_by(|a, b| a.counter.cmp(&b.counter))
-        .rev()
-        .take(2)
-        .map(|monkey| monkey.counter)
-        .product()
-}
-fn lcm(numbers: &[u64]) -> u64 {
-    let mut lcm = 1;
-    for &number in numbers {
-        lcm = lcm * number / gcd(lcm, number);
+
+    fn monkey_business(&self) -> usize {
+        self.monkeys
+            .iter()
+            .map(|monkey| monkey.counter)
+            .sorted()
+            .rev()
+            .take(2)
+            .product()
     }
-    lcm
 }
 
-fn gcd(a: u64, b: u64) -> u64 {
-    if b == 0 {
-        a
-    } else {
-        gcd(b, a % b)
+impl<const DIV3: bool> Simulation for KeepAway<DIV3> {
+    type Snapshot = (usize, Vec<(Items, usize)>);
+
+    fn step(&mut self) {
+        for index in 0..self.monkeys.len() {
+            let to_throw = self.monkeys[index].inspect::<DIV3>(self.divisor);
+            throw_items(&mut self.monkeys, to_throw);
+        }
+        self.round += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.round == self.rounds
     }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (
+            self.round,
+            self.monkeys
+                .iter()
+                .map(|monkey| (monkey.items.clone(), monkey.counter))
+                .collect(),
+        )
+    }
+
+    fn restore(&mut self, (round, monkeys): Self::Snapshot) {
+        self.round = round;
+        for (monkey, (items, counter)) in self.monkeys.iter_mut().zip(monkeys) {
+            monkey.items = items;
+            monkey.counter = counter;
+        }
+    }
+}
+
+pub fn solve_part1(input: &str) -> usize {
+    let mut keep_away = KeepAway::<true>::new(parse(input), u64::MAX, 20);
+    keep_away.run_to_end();
+    keep_away.monkey_business()
 }
 
 pub fn solve_part2(input: &str) -> usize {
-    let mut monkeys = parse(input);
+    let monkeys = parse(input);
     let lcm = lcm(&monkeys
         .iter()
         .map(|monkey| monkey.test.divisibility_factor)
         .collect_vec());
-    for _ in 0..10_000 {
-        for index in 0..monkeys.len() {
-            let to_throw = monkeys[index].inspect::<false>(lcm);
-            throw_items(&mut monkeys, to_throw);
-        }
-    }
-    monkeys
-        .into_iter()
-        .sorted_by(|a, b| a.counter.cmp(&b.counter))
-        .rev()
-        .take(2)
-        .map(|monkey| monkey.counter)
-        .product()
+    let mut keep_away = KeepAway::<false>::new(monkeys, lcm, 10_000);
+    keep_away.run_to_end();
+    keep_away.monkey_business()
 }
 
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
 
-    use crate::day11::{solve_part1, solve_part2};
+    use crate::{
+        day11::{parse, solve_part1, solve_part2, KeepAway},
+        simulation::Recorder,
+    };
 
     const INPUT: &str = "Monkey 0:
   Starting items: 79, 98
@@ -379,4 +391,17 @@ Monkey 3:
     fn part2() {
         assert_eq!(solve_part2(INPUT), 2_713_310_158);
     }
+
+    #[test]
+    fn rewind() {
+        let mut recorder = Recorder::new(KeepAway::<true>::new(parse(INPUT), u64::MAX, 20), 20);
+        recorder.run_to_end();
+        let (round, monkeys) = recorder.get(1).unwrap();
+        assert_eq!(*round, 1);
+        let worry_levels = monkeys[0].0 .0.iter().map(|item| item.worry_level);
+        assert!(worry_levels.eq([20, 23, 27, 26]));
+        assert!(recorder.rewind_to(1));
+        recorder.run_to_end();
+        assert_eq!(recorder.simulation().monkey_business(), 10605);
+    }
 }
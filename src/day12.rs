@@ -1,7 +1,9 @@
-use std::{fmt, ops::Index, str::FromStr};
+use std::{fmt, fmt::Write, ops::Index, str::FromStr};
 
 use pathfinding::prelude::bfs;
 
+use crate::instrument;
+
 struct Map {
     buffer: Vec<Point>,
     width: usize,
@@ -12,7 +14,7 @@ impl fmt::Debug for Map {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut map = String::new();
         for (i, point) in self.buffer.iter().enumerate() {
-            map.push_str(&format!("{point:?}"));
+            write!(map, "{point:?}")?;
             if i + 1 % self.width == 0 {
                 map.push('\n');
             }
@@ -72,6 +74,7 @@ impl Index<Coordinate> for Map {
 
 impl Map {
     fn successors(&self, coordinate: Coordinate) -> Vec<Coordinate> {
+        instrument::count("BFS nodes expanded");
         let point = self[coordinate];
         let neighbors = [
             Coordinate {
@@ -3,12 +3,24 @@ use std::{cmp::Ordering, collections::VecDeque, str::FromStr};
 use itertools::Itertools;
 use serde_json::Value;
 
-#[derive(Debug, Clone)]
+use crate::instrument;
+
+#[derive(Debug)]
 enum Packets {
     Array(VecDeque<Packets>),
     Number(u8),
 }
 
+impl Clone for Packets {
+    fn clone(&self) -> Self {
+        instrument::count("Packets clones");
+        match self {
+            Self::Array(packets) => Self::Array(packets.clone()),
+            Self::Number(number) => Self::Number(*number),
+        }
+    }
+}
+
 impl From<Value> for Packets {
     fn from(value: Value) -> Self {
         match value {
@@ -35,6 +47,7 @@ impl Eq for Packets {}
 
 impl Ord for Packets {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        instrument::count("Packets::cmp calls");
         match (self.clone(), other.clone()) {
             (Packets::Array(mut left), Packets::Array(mut right)) => {
                 match (left.pop_front(), right.pop_front()) {
@@ -4,37 +4,49 @@ use std::fmt;
 
 use itertools::Itertools;
 
+use crate::{geometry::Coordinate, instrument, simulation::Simulation};
+
 #[derive(Debug)]
 struct Map {
     buffer: HashMap<Coordinate, Point>,
     maximum_depth: i32,
 }
 
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min_x, max_x) = self
+            .buffer
+            .keys()
+            .map(|coordinate| coordinate.x)
+            .minmax()
+            .into_option()
+            .unwrap_or_default();
+        let max_y = self.buffer.keys().map(|coordinate| coordinate.y).max();
+        for y in 0..=max_y.unwrap_or_default() {
+            for x in min_x..=max_x {
+                match self.buffer.get(&Coordinate { x, y }) {
+                    Some(point) => write!(f, "{point:?}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            if Some(y) != max_y {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl FromStr for Map {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Map::construct(
-            s.lines()
-                .map(Path::from_str)
-                .collect::<Result<_, _>>()
-                .map_err(|_| ())?,
+            s.lines().map(Path::from_str).collect::<Result<_, _>>()?,
         ))
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Coordinate {
-    x: i32,
-    y: i32,
-}
-
-impl fmt::Debug for Coordinate {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("").field(&self.x).field(&self.y).finish()
-    }
-}
-
 impl Coordinate {
     const fn down(self) -> Coordinate {
         Coordinate {
@@ -58,23 +70,9 @@ impl Coordinate {
     }
 }
 
-impl FromStr for Coordinate {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (x, y) = s.split_once(',').ok_or(())?;
-        Ok(Coordinate {
-            x: x.parse().map_err(|_| ())?,
-            y: y.parse().map_err(|_| ())?,
-        })
-    }
-}
-
 impl Coordinate {
     fn from_to(from: Coordinate, to: Coordinate) -> Vec<Coordinate> {
-        if from.x != to.x && from.y != to.y {
-            panic!()
-        }
+        assert!(from.x == to.x || from.y == to.y);
 
         if from.x == to.x {
             let [f, t] = {
@@ -121,8 +119,7 @@ impl FromStr for Path {
             s.split("->")
                 .map(str::trim)
                 .map(Coordinate::from_str)
-                .collect::<Result<_, _>>()
-                .map_err(|_| ())?,
+                .collect::<Result<_, _>>()?,
         ))
     }
 }
@@ -151,8 +148,10 @@ impl Map {
     }
 
     fn sand_coordinate(&self) -> Option<Coordinate> {
+        instrument::count("Map::sand_coordinate calls");
         self.buffer
             .iter()
+            .inspect(|_| instrument::count("Map::sand_coordinate entries scanned"))
             .find(|(_, v)| v == &&mut Point::Sand)
             .map(|(coordinate, _)| *coordinate)
     }
@@ -162,41 +161,97 @@ impl Map {
     }
 
     fn tick(&mut self, coordinate: Coordinate) {
+        // Counted next to each call, so that every branch is counted alike.
+        const OPERATIONS: &str = "Map::tick HashMap operations";
+        instrument::count(OPERATIONS);
         self.buffer.remove(&coordinate);
-        if let Entry::Vacant(entry) = self.buffer.entry(coordinate.down()) {
-            entry.insert(Point::Sand);
-        } else if let Entry::Vacant(entry) = self.buffer.entry(coordinate.down_left()) {
-            entry.insert(Point::Sand);
-        } else if let Entry::Vacant(entry) = self.buffer.entry(coordinate.down_right()) {
-            entry.insert(Point::Sand);
-        } else {
-            self.buffer.insert(coordinate, Point::RestSand);
+        for target in [
+            coordinate.down(),
+            coordinate.down_left(),
+            coordinate.down_right(),
+        ] {
+            instrument::count(OPERATIONS);
+            if let Entry::Vacant(entry) = self.buffer.entry(target) {
+                instrument::count(OPERATIONS);
+                entry.insert(Point::Sand);
+                return;
+            }
         }
+        instrument::count(OPERATIONS);
+        self.buffer.insert(coordinate, Point::RestSand);
     }
 
-    fn run(&mut self, end_goal: impl Fn(&Map, Coordinate) -> bool) -> usize {
-        loop {
-            if let Some(coordinate) = self.sand_coordinate() {
-                if end_goal(self, coordinate) {
-                    break self
-                        .buffer
-                        .values()
-                        .filter(|&&point| point == Point::RestSand)
-                        .count();
-                }
-                self.tick(coordinate);
+    fn rest_sand(&self) -> usize {
+        self.buffer
+            .values()
+            .filter(|&&point| point == Point::RestSand)
+            .count()
+    }
+
+    fn run(self, end_goal: impl Fn(&Map, Coordinate) -> bool) -> usize {
+        let mut pouring = Pouring {
+            map: self,
+            end_goal,
+            done: false,
+        };
+        pouring.run_to_end();
+        pouring.map.rest_sand()
+    }
+}
+
+/// Moves or spawns a single unit of sand per step, until `end_goal` holds for the falling sand.
+struct Pouring<F> {
+    map: Map,
+    end_goal: F,
+    done: bool,
+}
+
+impl<F: Fn(&Map, Coordinate) -> bool> Simulation for Pouring<F> {
+    type Snapshot = (HashMap<Coordinate, Point>, bool);
+
+    fn step(&mut self) {
+        if let Some(coordinate) = self.map.sand_coordinate() {
+            if (self.end_goal)(&self.map, coordinate) {
+                self.done = true;
             } else {
-                self.spawn_sand();
+                self.map.tick(coordinate);
             }
+        } else {
+            self.map.spawn_sand();
         }
     }
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.map.buffer.clone(), self.done)
+    }
+
+    fn restore(&mut self, (buffer, done): Self::Snapshot) {
+        self.map.buffer = buffer;
+        self.done = done;
+    }
+}
+
+fn into_the_abyss(map: &Map, coordinate: Coordinate) -> bool {
+    coordinate.y > map.maximum_depth
 }
 
 pub fn solve_part1(input: &str) -> usize {
-    input
-        .parse::<Map>()
-        .unwrap()
-        .run(|map, coordinate| coordinate.y > map.maximum_depth)
+    input.parse::<Map>().unwrap().run(into_the_abyss)
+}
+
+/// Draws the cave once the sand of part 1 has stopped coming to rest.
+pub fn render(input: &str) -> String {
+    let mut pouring = Pouring {
+        map: input.parse::<Map>().unwrap(),
+        end_goal: into_the_abyss,
+        done: false,
+    };
+    pouring.run_to_end();
+    pouring.map.to_string()
 }
 
 pub fn solve_part2(input: &str) -> usize {
@@ -225,7 +280,13 @@ pub fn solve_part2(input: &str) -> usize {
 mod tests {
     use pretty_assertions::assert_eq;
 
-    use crate::day14::{solve_part1, solve_part2};
+    use crate::{
+        day14::{
+            into_the_abyss, render, solve_part1, solve_part2, Coordinate, Map, Point, Pouring,
+        },
+        instrument,
+        simulation::Recorder,
+    };
 
     const INPUT: &str = "498,4 -> 498,6 -> 496,6
 503,4 -> 502,4 -> 502,9 -> 494,9";
@@ -239,4 +300,71 @@ mod tests {
     fn part2() {
         assert_eq!(solve_part2(INPUT), 93);
     }
+
+    #[test]
+    fn rewind() {
+        let pouring = Pouring {
+            map: INPUT.parse::<Map>().unwrap(),
+            end_goal: into_the_abyss,
+            done: false,
+        };
+        let mut recorder = Recorder::new(pouring, 16);
+        recorder.run_to_end();
+        assert_eq!(recorder.simulation().map.rest_sand(), 24);
+        let oldest = recorder.oldest_step();
+        assert!(recorder.rewind_to(oldest));
+        assert!(!recorder.simulation().done);
+        recorder.run_to_end();
+        assert_eq!(recorder.into_inner().map.rest_sand(), 24);
+
+        // The first unit of sand is spawned, falls 8 times and comes to rest above the rock.
+        let pouring = Pouring {
+            map: INPUT.parse::<Map>().unwrap(),
+            end_goal: into_the_abyss,
+            done: false,
+        };
+        let mut recorder = Recorder::new(pouring, 16);
+        for _ in 0..10 {
+            recorder.step();
+        }
+        let rest = Coordinate { x: 500, y: 8 };
+        assert_eq!(recorder.get(9).unwrap().0.get(&rest), Some(&Point::Sand));
+        assert_eq!(
+            recorder.simulation().map.buffer.get(&rest),
+            Some(&Point::RestSand)
+        );
+    }
+
+    #[test]
+    fn tick_operations() {
+        // Falling down, down to the left, down to the right, and coming to rest on the floor.
+        for (x, y, operations) in [(500, 0, 3), (498, 3, 4), (503, 3, 5), (500, 8, 5)] {
+            let mut map = INPUT.parse::<Map>().unwrap();
+            let sand = Coordinate { x, y };
+            let ((), counters) = instrument::measure(|| map.tick(sand));
+            if cfg!(feature = "instrument") {
+                assert_eq!(counters, [("Map::tick HashMap operations", operations)]);
+            } else {
+                assert!(counters.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn render_cave() {
+        assert_eq!(
+            render(INPUT),
+            "...........
+...........
+.......o...
+......ooo..
+.....#ooo##
+....o#ooo#.
+...###ooo#.
+.....oooo#.
+..o.ooooo#.
+.#########.
+~.........."
+        );
+    }
 }
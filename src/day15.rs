@@ -0,0 +1,177 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use itertools::Itertools;
+
+use crate::geometry::Coordinate;
+
+#[derive(Debug)]
+struct Sensor {
+    position: Coordinate,
+    beacon: Coordinate,
+    // No other beacon can be within this distance of the sensor.
+    radius: u32,
+}
+
+impl FromStr for Sensor {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y, beacon_x, beacon_y) = s
+            .split(|c: char| c != '-' && !c.is_ascii_digit())
+            .filter(|number| !number.is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<i32>, _>>()
+            .map_err(|_| "Cannot parse coordinate!")?
+            .into_iter()
+            .collect_tuple()
+            .ok_or("Expected a sensor and a beacon!")?;
+        let position = Coordinate::new(x, y);
+        let beacon = Coordinate::new(beacon_x, beacon_y);
+        Ok(Sensor {
+            position,
+            beacon,
+            radius: position.manhattan_distance(beacon),
+        })
+    }
+}
+
+impl Sensor {
+    /// The positions on `row` that are covered by this sensor.
+    fn coverage(&self, row: i32) -> Option<RangeInclusive<i32>> {
+        let reach = i32::try_from(self.radius.checked_sub(self.position.y.abs_diff(row))?).ok()?;
+        Some(self.position.x - reach..=self.position.x + reach)
+    }
+}
+
+fn parse(input: &str) -> Vec<Sensor> {
+    input.lines().map(Sensor::from_str).try_collect().unwrap()
+}
+
+/// Merges overlapping or adjacent ranges, returning them sorted.
+fn merge(mut ranges: Vec<RangeInclusive<i32>>) -> Vec<RangeInclusive<i32>> {
+    ranges.sort_unstable_by_key(|range| *range.start());
+    let mut merged: Vec<RangeInclusive<i32>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if *range.start() <= last.end() + 1 => {
+                *last = *last.start()..=*last.end().max(range.end());
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+fn covered_ranges(sensors: &[Sensor], row: i32) -> Vec<RangeInclusive<i32>> {
+    merge(
+        sensors
+            .iter()
+            .filter_map(|sensor| sensor.coverage(row))
+            .collect(),
+    )
+}
+
+/// Counts the positions on `row` where the distress beacon cannot be.
+fn excluded_positions(sensors: &[Sensor], row: i32) -> usize {
+    let ranges = covered_ranges(sensors, row);
+    let covered: usize = ranges
+        .iter()
+        .map(|range| range.start().abs_diff(*range.end()) as usize + 1)
+        .sum();
+    // Known beacons are not excluded, they are just not the distress beacon.
+    let beacons = sensors
+        .iter()
+        .map(|sensor| sensor.beacon)
+        .filter(|beacon| beacon.y == row && ranges.iter().any(|range| range.contains(&beacon.x)))
+        .unique()
+        .count();
+    covered - beacons
+}
+
+/// Finds the only position within `0..=bound` on both axes that no sensor covers.
+fn distress_beacon(sensors: &[Sensor], bound: i32) -> Option<Coordinate> {
+    (0..=bound).find_map(|row| {
+        let mut x = 0;
+        for range in covered_ranges(sensors, row) {
+            if *range.start() > x {
+                break;
+            }
+            x = x.max(range.end() + 1);
+        }
+        (x <= bound).then_some(Coordinate::new(x, row))
+    })
+}
+
+fn tuning_frequency(beacon: Coordinate) -> i64 {
+    i64::from(beacon.x) * 4_000_000 + i64::from(beacon.y)
+}
+
+pub fn excluded_on_row(input: &str, row: i32) -> usize {
+    excluded_positions(&parse(input), row)
+}
+
+pub fn distress_frequency(input: &str, bound: i32) -> i64 {
+    tuning_frequency(distress_beacon(&parse(input), bound).unwrap())
+}
+
+pub fn solve_part1(input: &str) -> usize {
+    excluded_on_row(input, 2_000_000)
+}
+
+pub fn solve_part2(input: &str) -> i64 {
+    distress_frequency(input, 4_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        day15::{distress_frequency, excluded_on_row, merge, parse},
+        geometry::Coordinate,
+    };
+
+    const INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    #[test]
+    fn parsing() {
+        let sensors = parse(INPUT);
+        assert_eq!(sensors.len(), 14);
+        assert_eq!(sensors[0].position, Coordinate::new(2, 18));
+        assert_eq!(sensors[0].beacon, Coordinate::new(-2, 15));
+        assert_eq!(sensors[6].radius, 9);
+    }
+
+    #[test]
+    fn merging() {
+        assert_eq!(
+            merge(vec![12..=12, 2..=14, 2..=2, -2..=2, 16..=24, 14..=18]),
+            [-2..=24]
+        );
+        assert_eq!(merge(vec![5..=6, 0..=3, 3..=3]), [0..=3, 5..=6]);
+        assert_eq!(merge(vec![4..=6, 0..=3]), [0..=6]);
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(excluded_on_row(INPUT, 10), 26);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(distress_frequency(INPUT, 20), 56_000_011);
+    }
+}
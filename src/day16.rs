@@ -0,0 +1,201 @@
+use std::{collections::HashMap, str::FromStr};
+
+use itertools::Itertools;
+use pathfinding::prelude::dijkstra_all;
+
+#[derive(Debug)]
+struct Valve {
+    name: String,
+    flow_rate: u32,
+    tunnels: Vec<String>,
+}
+
+impl FromStr for Valve {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (valve, tunnels) = s.split_once(';').ok_or("Missing tunnels!")?;
+        let mut words = valve.split_whitespace();
+        let name = words.nth(1).ok_or("Missing valve name!")?.to_owned();
+        let flow_rate = words
+            .last()
+            .and_then(|rate| rate.strip_prefix("rate="))
+            .and_then(|rate| rate.parse().ok())
+            .ok_or("Cannot parse flow rate!")?;
+        // Either "tunnels lead to valves AA, BB" or "tunnel leads to valve AA".
+        let tunnels = tunnels
+            .split_whitespace()
+            .skip(4)
+            .map(|tunnel| tunnel.trim_end_matches(',').to_owned())
+            .collect();
+        Ok(Valve {
+            name,
+            flow_rate,
+            tunnels,
+        })
+    }
+}
+
+/// Only the valves worth opening, with the time it takes to walk between them.
+struct Network {
+    flow_rates: Vec<u32>,
+    // Includes the starting valve as the last row and column.
+    distances: Vec<Vec<u32>>,
+}
+
+impl Network {
+    const START: &'static str = "AA";
+
+    fn new(valves: &[Valve]) -> Self {
+        let tunnels: HashMap<&str, &[String]> = valves
+            .iter()
+            .map(|valve| (valve.name.as_str(), valve.tunnels.as_slice()))
+            .collect();
+        let useful = valves
+            .iter()
+            .filter(|valve| valve.flow_rate > 0)
+            .sorted_by(|a, b| a.name.cmp(&b.name))
+            .collect_vec();
+        let nodes = useful
+            .iter()
+            .map(|valve| valve.name.as_str())
+            .chain([Self::START])
+            .collect_vec();
+
+        let distances = nodes
+            .iter()
+            .map(|&from| {
+                let reachable = dijkstra_all(&from, |&valve| {
+                    tunnels[valve].iter().map(|tunnel| (tunnel.as_str(), 1))
+                });
+                nodes
+                    .iter()
+                    .map(|to| if *to == from { 0 } else { reachable[to].1 })
+                    .collect()
+            })
+            .collect();
+
+        Network {
+            flow_rates: useful.iter().map(|valve| valve.flow_rate).collect(),
+            distances,
+        }
+    }
+
+    fn start(&self) -> usize {
+        self.flow_rates.len()
+    }
+
+    /// Records the most pressure that can be released for every set of opened valves.
+    /// Each set is a bitmask over the indices of `flow_rates`.
+    fn explore(
+        &self,
+        position: usize,
+        time: u32,
+        opened: usize,
+        released: u32,
+        best: &mut HashMap<usize, u32>,
+    ) {
+        let entry = best.entry(opened).or_default();
+        *entry = (*entry).max(released);
+
+        for (valve, &flow_rate) in self.flow_rates.iter().enumerate() {
+            if opened & (1 << valve) != 0 {
+                continue;
+            }
+            // Walking there and opening the valve.
+            let cost = self.distances[position][valve] + 1;
+            if let Some(remaining) = time.checked_sub(cost).filter(|&remaining| remaining > 0) {
+                self.explore(
+                    valve,
+                    remaining,
+                    opened | (1 << valve),
+                    released + remaining * flow_rate,
+                    best,
+                );
+            }
+        }
+    }
+
+    fn best_releases(&self, time: u32) -> HashMap<usize, u32> {
+        let mut best = HashMap::new();
+        self.explore(self.start(), time, 0, 0, &mut best);
+        best
+    }
+}
+
+fn parse(input: &str) -> Network {
+    let valves: Vec<Valve> = input.lines().map(Valve::from_str).try_collect().unwrap();
+    Network::new(&valves)
+}
+
+pub fn solve_part1(input: &str) -> u32 {
+    parse(input).best_releases(30).into_values().max().unwrap()
+}
+
+pub fn solve_part2(input: &str) -> u32 {
+    let network = parse(input);
+    let best = network.best_releases(26);
+
+    // The best release of any subset of each set of valves,
+    // so that the elephant can take the best of whatever is left.
+    let all = (1_usize << network.flow_rates.len()) - 1;
+    let mut best_subset = vec![0; 1 << network.flow_rates.len()];
+    for (&opened, &released) in &best {
+        best_subset[opened] = released;
+    }
+    for valve in 0..network.flow_rates.len() {
+        for set in 0..best_subset.len() {
+            if set & (1 << valve) != 0 {
+                best_subset[set] = best_subset[set].max(best_subset[set ^ (1 << valve)]);
+            }
+        }
+    }
+
+    best.iter()
+        .map(|(&opened, &released)| released + best_subset[all & !opened])
+        .max()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::day16::{parse, solve_part1, solve_part2, Valve};
+
+    const INPUT: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+    #[test]
+    fn parsing() {
+        let valve: Valve = "Valve HH has flow rate=22; tunnel leads to valve GG"
+            .parse()
+            .unwrap();
+        assert_eq!(valve.name, "HH");
+        assert_eq!(valve.flow_rate, 22);
+        assert_eq!(valve.tunnels, ["GG"]);
+
+        let network = parse(INPUT);
+        // BB, CC, DD, EE, HH and JJ, then AA.
+        assert_eq!(network.flow_rates, [13, 2, 20, 3, 22, 21]);
+        assert_eq!(network.distances[network.start()], [1, 2, 1, 2, 5, 2, 0]);
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(INPUT), 1651);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(INPUT), 1707);
+    }
+}
@@ -0,0 +1,320 @@
+use std::{collections::HashMap, fmt};
+
+use crate::simulation::Simulation;
+
+/// The chamber is seven units wide, so a row fits in a byte.
+/// The leftmost column is the highest of those seven bits.
+const WIDTH: u32 = 7;
+const LEFT_WALL: u8 = 1 << (WIDTH - 1);
+const RIGHT_WALL: u8 = 1;
+
+/// The five rock shapes in the order they fall, bottom row first,
+/// already two units away from the left wall.
+const SHAPES: [[u8; 4]; 5] = [
+    [0b001_1110, 0, 0, 0],
+    [0b000_1000, 0b001_1100, 0b000_1000, 0],
+    [0b001_1100, 0b000_0100, 0b000_0100, 0],
+    [0b001_0000, 0b001_0000, 0b001_0000, 0b001_0000],
+    [0b001_1000, 0b001_1000, 0, 0],
+];
+
+/// How many of the top rows are compared when looking for a cycle.
+const PROFILE_DEPTH: usize = 32;
+
+#[derive(Debug, Clone, Copy)]
+enum Jet {
+    Left,
+    Right,
+}
+
+fn parse(input: &str) -> Vec<Jet> {
+    input
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '<' => Jet::Left,
+            '>' => Jet::Right,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Point {
+    Rock,
+    FallingRock,
+    Air,
+}
+
+impl fmt::Debug for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rock => write!(f, "#"),
+            Self::FallingRock => write!(f, "~"),
+            Self::Air => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Rock {
+    shape: [u8; 4],
+    // The row of the bottom of the shape.
+    y: usize,
+}
+
+impl Rock {
+    fn rows(self) -> impl Iterator<Item = (usize, u8)> {
+        (self.y..).zip(self.shape).filter(|&(_, row)| row != 0)
+    }
+}
+
+struct Chamber<'a> {
+    rows: Vec<u8>,
+    jets: &'a [Jet],
+    next_jet: usize,
+    rocks: usize,
+    falling: Option<Rock>,
+    limit: usize,
+}
+
+impl<'a> Chamber<'a> {
+    fn new(jets: &'a [Jet], limit: usize) -> Self {
+        Self {
+            rows: Vec::new(),
+            jets,
+            next_jet: 0,
+            rocks: 0,
+            falling: None,
+            limit,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn fits(&self, rock: Rock) -> bool {
+        rock.rows()
+            .all(|(y, row)| self.rows.get(y).is_none_or(|&rows| rows & row == 0))
+    }
+
+    fn push(&self, rock: Rock, jet: Jet) -> Rock {
+        let shape = match jet {
+            Jet::Left if rock.shape.iter().all(|row| row & LEFT_WALL == 0) => {
+                rock.shape.map(|row| row << 1)
+            }
+            Jet::Right if rock.shape.iter().all(|row| row & RIGHT_WALL == 0) => {
+                rock.shape.map(|row| row >> 1)
+            }
+            _ => return rock,
+        };
+        let pushed = Rock { shape, y: rock.y };
+        if self.fits(pushed) {
+            pushed
+        } else {
+            rock
+        }
+    }
+
+    fn settle(&mut self, rock: Rock) {
+        for (y, row) in rock.rows() {
+            if y == self.rows.len() {
+                self.rows.push(row);
+            } else {
+                self.rows[y] |= row;
+            }
+        }
+        self.rocks += 1;
+    }
+
+    /// The shape of the top of the tower, along with everything that decides what happens next.
+    fn state(&self) -> (usize, usize, Vec<u8>) {
+        let profile = self
+            .rows
+            .iter()
+            .rev()
+            .take(PROFILE_DEPTH)
+            .copied()
+            .collect();
+        (self.rocks % SHAPES.len(), self.next_jet, profile)
+    }
+
+    fn point(&self, x: u32, y: usize) -> Point {
+        let bit = LEFT_WALL >> x;
+        if self
+            .falling
+            .is_some_and(|rock| rock.rows().any(|(row_y, row)| row_y == y && row & bit != 0))
+        {
+            Point::FallingRock
+        } else if self.rows.get(y).is_some_and(|row| row & bit != 0) {
+            Point::Rock
+        } else {
+            Point::Air
+        }
+    }
+}
+
+impl fmt::Display for Chamber<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let top = self
+            .falling
+            .map_or(self.height(), |rock| rock.rows().last().unwrap().0 + 1);
+        for y in (0..top).rev() {
+            write!(f, "|")?;
+            for x in 0..WIDTH {
+                write!(f, "{:?}", self.point(x, y))?;
+            }
+            writeln!(f, "|")?;
+        }
+        write!(f, "+-------+")
+    }
+}
+
+/// Spawns a rock, or pushes and drops the falling one, per step.
+impl Simulation for Chamber<'_> {
+    type Snapshot = (Vec<u8>, usize, usize, Option<Rock>);
+
+    fn step(&mut self) {
+        let Some(rock) = self.falling else {
+            self.falling = Some(Rock {
+                shape: SHAPES[self.rocks % SHAPES.len()],
+                y: self.height() + 3,
+            });
+            return;
+        };
+
+        let rock = self.push(rock, self.jets[self.next_jet]);
+        self.next_jet = (self.next_jet + 1) % self.jets.len();
+
+        let fallen = rock.y.checked_sub(1).map(|y| Rock {
+            shape: rock.shape,
+            y,
+        });
+        if let Some(fallen) = fallen.filter(|&fallen| self.fits(fallen)) {
+            self.falling = Some(fallen);
+        } else {
+            self.settle(rock);
+            self.falling = None;
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.rocks == self.limit
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.rows.clone(), self.next_jet, self.rocks, self.falling)
+    }
+
+    fn restore(&mut self, (rows, next_jet, rocks, falling): Self::Snapshot) {
+        self.rows = rows;
+        self.next_jet = next_jet;
+        self.rocks = rocks;
+        self.falling = falling;
+    }
+}
+
+/// Drops `rocks` rocks, skipping ahead once the top of the tower starts repeating.
+fn tower_height(jets: &[Jet], rocks: usize) -> usize {
+    let mut chamber = Chamber::new(jets, rocks);
+    let mut seen = HashMap::new();
+    let mut skipped_height = 0;
+
+    while !chamber.is_done() {
+        let settled = chamber.rocks;
+        chamber.step();
+        if chamber.rocks == settled || skipped_height > 0 {
+            continue;
+        }
+
+        match seen.insert(chamber.state(), (chamber.rocks, chamber.height())) {
+            Some((previous_rocks, previous_height)) if chamber.height() >= PROFILE_DEPTH => {
+                let cycle = chamber.rocks - previous_rocks;
+                let cycles = (rocks - chamber.rocks) / cycle;
+                skipped_height = cycles * (chamber.height() - previous_height);
+                chamber.limit -= cycles * cycle;
+            }
+            _ => {}
+        }
+    }
+
+    chamber.height() + skipped_height
+}
+
+/// Draws the tower after the 2022 rocks of part 1.
+pub fn render(input: &str) -> String {
+    let jets = parse(input);
+    let mut chamber = Chamber::new(&jets, 2022);
+    chamber.run_to_end();
+    chamber.to_string()
+}
+
+pub fn solve_part1(input: &str) -> usize {
+    tower_height(&parse(input), 2022)
+}
+
+pub fn solve_part2(input: &str) -> usize {
+    tower_height(&parse(input), 1_000_000_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        day17::{parse, solve_part1, solve_part2, tower_height, Chamber},
+        simulation::{Recorder, Simulation},
+    };
+
+    const INPUT: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn falling() {
+        let jets = parse(INPUT);
+        let mut recorder = Recorder::new(Chamber::new(&jets, 2), 8);
+        // The first rock is spawned, then pushed and dropped four times.
+        for _ in 0..5 {
+            recorder.step();
+        }
+        assert_eq!(recorder.simulation().to_string(), "|..####.|\n+-------+");
+
+        recorder.step();
+        assert_eq!(
+            recorder.simulation().to_string(),
+            "|...~...|
+|..~~~..|
+|...~...|
+|.......|
+|.......|
+|.......|
+|..####.|
++-------+"
+        );
+
+        recorder.run_to_end();
+        assert_eq!(recorder.simulation().height(), 4);
+        assert!(recorder.rewind_to(5));
+        assert_eq!(recorder.simulation().height(), 1);
+    }
+
+    #[test]
+    fn without_cycles() {
+        let jets = parse(INPUT);
+        for rocks in [1, 2, 10, 2022] {
+            let mut chamber = Chamber::new(&jets, rocks);
+            chamber.run_to_end();
+            assert_eq!(tower_height(&jets, rocks), chamber.height());
+        }
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(INPUT), 3068);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(INPUT), 1_514_285_714_288);
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use crate::geometry::Coordinate3;
+
+fn parse(input: &str) -> HashSet<Coordinate3> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+fn exposed_faces(cubes: &HashSet<Coordinate3>) -> usize {
+    cubes
+        .iter()
+        .flat_map(|cube| cube.neighbours())
+        .filter(|neighbour| !cubes.contains(neighbour))
+        .count()
+}
+
+/// Floods the air around the droplet, within a box one unit larger than it on every side,
+/// counting every cube face the water runs into.
+fn exterior_faces(cubes: &HashSet<Coordinate3>) -> usize {
+    let Some(first) = cubes.iter().next() else {
+        return 0;
+    };
+    let (min, max) = cubes.iter().fold((*first, *first), |(min, max), cube| {
+        (
+            Coordinate3::new(min.x.min(cube.x), min.y.min(cube.y), min.z.min(cube.z)),
+            Coordinate3::new(max.x.max(cube.x), max.y.max(cube.y), max.z.max(cube.z)),
+        )
+    });
+    let min = min - Coordinate3::new(1, 1, 1);
+    let max = max + Coordinate3::new(1, 1, 1);
+    let inside = |c: &Coordinate3| {
+        (min.x..=max.x).contains(&c.x)
+            && (min.y..=max.y).contains(&c.y)
+            && (min.z..=max.z).contains(&c.z)
+    };
+
+    let mut water = HashSet::from([min]);
+    let mut queue = vec![min];
+    let mut faces = 0;
+    while let Some(position) = queue.pop() {
+        for neighbour in position.neighbours().filter(inside) {
+            if cubes.contains(&neighbour) {
+                faces += 1;
+            } else if water.insert(neighbour) {
+                queue.push(neighbour);
+            }
+        }
+    }
+    faces
+}
+
+pub fn solve_part1(input: &str) -> usize {
+    exposed_faces(&parse(input))
+}
+
+pub fn solve_part2(input: &str) -> usize {
+    exterior_faces(&parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day18::{exposed_faces, exterior_faces, parse, solve_part1, solve_part2};
+
+    const INPUT: &str = "2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
+    #[test]
+    fn two_cubes() {
+        let cubes = parse("1,1,1\n2,1,1");
+        assert_eq!(exposed_faces(&cubes), 10);
+        assert_eq!(exterior_faces(&cubes), 10);
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(INPUT), 64);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(INPUT), 58);
+    }
+}
@@ -0,0 +1,176 @@
+use std::{str::FromStr, thread};
+
+use itertools::Itertools;
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Blueprint {
+    id: u32,
+    // The resources each kind of robot costs, indexed like the resources themselves.
+    costs: [[u32; 4]; 4],
+}
+
+impl FromStr for Blueprint {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian) = s
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|number| !number.is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<u32>, _>>()
+            .map_err(|_| "Cannot parse cost!")?
+            .into_iter()
+            .collect_tuple()
+            .ok_or("Expected an id and six costs!")?;
+        Ok(Blueprint {
+            id,
+            costs: [
+                [ore, 0, 0, 0],
+                [clay, 0, 0, 0],
+                [obsidian_ore, obsidian_clay, 0, 0],
+                [geode_ore, 0, geode_obsidian, 0],
+            ],
+        })
+    }
+}
+
+impl Blueprint {
+    /// Only one robot can be built per minute, so there is no use in collecting
+    /// more of a resource per minute than the most expensive robot needs.
+    fn useful_robots(&self) -> [u32; 4] {
+        let mut useful = [u32::MAX; 4];
+        for resource in [ORE, CLAY, OBSIDIAN] {
+            useful[resource] = self.costs.iter().map(|cost| cost[resource]).max().unwrap();
+        }
+        useful
+    }
+
+    /// Branches on which robot to build next, waiting for as long as it takes to afford it.
+    fn search(
+        &self,
+        useful: [u32; 4],
+        time: u32,
+        robots: [u32; 4],
+        resources: [u32; 4],
+        best: &mut u32,
+    ) {
+        let idle = resources[GEODE] + robots[GEODE] * time;
+        *best = (*best).max(idle);
+        // Even a new geode robot every remaining minute cannot beat the best so far.
+        if idle + time * time.saturating_sub(1) / 2 <= *best {
+            return;
+        }
+
+        for robot in [GEODE, OBSIDIAN, CLAY, ORE] {
+            if robots[robot] >= useful[robot] {
+                continue;
+            }
+            let Some(wait) = (0..4)
+                .map(|resource| {
+                    let missing = self.costs[robot][resource].saturating_sub(resources[resource]);
+                    match (missing, robots[resource]) {
+                        (0, _) => Some(0),
+                        (_, 0) => None,
+                        (missing, rate) => Some(missing.div_ceil(rate)),
+                    }
+                })
+                .try_fold(0, |wait, resource| Some(wait.max(resource?)))
+            else {
+                continue;
+            };
+            // A robot built in the last minute never collects anything.
+            let Some(remaining) = time
+                .checked_sub(wait + 1)
+                .filter(|&remaining| remaining > 0)
+            else {
+                continue;
+            };
+
+            let mut next_robots = robots;
+            next_robots[robot] += 1;
+            let next_resources = std::array::from_fn(|resource| {
+                resources[resource] + robots[resource] * (wait + 1) - self.costs[robot][resource]
+            });
+            self.search(useful, remaining, next_robots, next_resources, best);
+        }
+    }
+
+    fn max_geodes(&self, time: u32) -> u32 {
+        let mut best = 0;
+        self.search(self.useful_robots(), time, [1, 0, 0, 0], [0; 4], &mut best);
+        best
+    }
+}
+
+fn parse(input: &str) -> Vec<Blueprint> {
+    input
+        .lines()
+        .map(Blueprint::from_str)
+        .try_collect()
+        .unwrap()
+}
+
+/// Evaluates every blueprint on its own thread.
+fn max_geodes(blueprints: &[Blueprint], time: u32) -> Vec<u32> {
+    thread::scope(|scope| {
+        blueprints
+            .iter()
+            .map(|blueprint| scope.spawn(move || blueprint.max_geodes(time)))
+            .collect_vec()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    })
+}
+
+pub fn solve_part1(input: &str) -> u32 {
+    let blueprints = parse(input);
+    blueprints
+        .iter()
+        .zip(max_geodes(&blueprints, 24))
+        .map(|(blueprint, geodes)| blueprint.id * geodes)
+        .sum()
+}
+
+pub fn solve_part2(input: &str) -> u32 {
+    let mut blueprints = parse(input);
+    blueprints.truncate(3);
+    max_geodes(&blueprints, 32).into_iter().product()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::day19::{max_geodes, parse, solve_part1, Blueprint};
+
+    const INPUT: &str = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
+    #[test]
+    fn parsing() {
+        assert_eq!(
+            parse(INPUT)[0],
+            Blueprint {
+                id: 1,
+                costs: [[4, 0, 0, 0], [2, 0, 0, 0], [3, 14, 0, 0], [2, 0, 7, 0]],
+            }
+        );
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(max_geodes(&parse(INPUT), 24), [9, 12]);
+        assert_eq!(solve_part1(INPUT), 33);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(max_geodes(&parse(INPUT), 32), [56, 62]);
+    }
+}
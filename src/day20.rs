@@ -0,0 +1,179 @@
+const DECRYPTION_KEY: i64 = 811_589_153;
+
+fn parse(input: &str) -> Vec<i64> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+/// The numbers in their mixed order, kept as a list of blocks of about `√n` indices
+/// into `numbers`, so that finding, removing and inserting a number are all `O(√n)`.
+struct Mixer {
+    numbers: Vec<i64>,
+    blocks: Vec<Vec<usize>>,
+    // The block each index into `numbers` currently lives in.
+    block_of: Vec<usize>,
+    block_size: usize,
+}
+
+impl Mixer {
+    fn new(numbers: Vec<i64>) -> Self {
+        let block_size = numbers.len().isqrt().max(1);
+        let mut mixer = Mixer {
+            blocks: vec![(0..numbers.len()).collect()],
+            block_of: vec![0; numbers.len()],
+            numbers,
+            block_size,
+        };
+        mixer.rebalance();
+        mixer
+    }
+
+    fn rebalance(&mut self) {
+        let order: Vec<usize> = self.blocks.concat();
+        self.blocks = order
+            .chunks(self.block_size)
+            .map(<[usize]>::to_vec)
+            .collect();
+        for (block, indices) in self.blocks.iter().enumerate() {
+            for &index in indices {
+                self.block_of[index] = block;
+            }
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> usize {
+        let block = self.block_of[index];
+        let offset = self.blocks[block]
+            .iter()
+            .position(|&other| other == index)
+            .unwrap();
+        self.blocks[block].remove(offset);
+        self.blocks[..block].iter().map(Vec::len).sum::<usize>() + offset
+    }
+
+    fn insert(&mut self, mut position: usize, index: usize) {
+        let last = self.blocks.len() - 1;
+        for (block, indices) in self.blocks.iter_mut().enumerate() {
+            if position <= indices.len() && (position < indices.len() || block == last) {
+                indices.insert(position, index);
+                self.block_of[index] = block;
+                if indices.len() > 2 * self.block_size {
+                    self.rebalance();
+                }
+                return;
+            }
+            position -= indices.len();
+        }
+    }
+
+    /// Moves every number by its own value, in their original order.
+    fn mix(&mut self) {
+        if self.numbers.len() < 2 {
+            return;
+        }
+        // Moving a number a full lap past the others brings it back where it was.
+        let others = i64::try_from(self.numbers.len() - 1).unwrap();
+        for index in 0..self.numbers.len() {
+            let position = i64::try_from(self.remove(index)).unwrap();
+            let target = (position + self.numbers[index]).rem_euclid(others);
+            self.insert(usize::try_from(target).unwrap(), index);
+        }
+    }
+
+    fn mixed(&self) -> impl Iterator<Item = i64> + '_ {
+        self.blocks
+            .iter()
+            .flatten()
+            .map(|&index| self.numbers[index])
+    }
+}
+
+fn grove_coordinates(mixer: &Mixer) -> i64 {
+    let numbers: Vec<i64> = mixer.mixed().collect();
+    let zero = numbers.iter().position(|&number| number == 0).unwrap();
+    [1000, 2000, 3000]
+        .into_iter()
+        .map(|offset| numbers[(zero + offset) % numbers.len()])
+        .sum()
+}
+
+pub fn solve_part1(input: &str) -> i64 {
+    let mut mixer = Mixer::new(parse(input));
+    mixer.mix();
+    grove_coordinates(&mixer)
+}
+
+pub fn solve_part2(input: &str) -> i64 {
+    let numbers = parse(input)
+        .into_iter()
+        .map(|number| number * DECRYPTION_KEY)
+        .collect();
+    let mut mixer = Mixer::new(numbers);
+    for _ in 0..10 {
+        mixer.mix();
+    }
+    grove_coordinates(&mixer)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::day20::{parse, solve_part1, solve_part2, Mixer};
+
+    const INPUT: &str = "1
+2
+-3
+3
+-2
+0
+4";
+
+    /// Rotates the mixed list to start at zero, as the circle has no real start.
+    fn from_zero(mixer: &Mixer) -> Vec<i64> {
+        let mut numbers: Vec<i64> = mixer.mixed().collect();
+        let zero = numbers.iter().position(|&number| number == 0).unwrap();
+        numbers.rotate_left(zero);
+        numbers
+    }
+
+    #[test]
+    fn mixing() {
+        let mut mixer = Mixer::new(parse(INPUT));
+        mixer.mix();
+        assert_eq!(from_zero(&mixer), [0, 3, -2, 1, 2, -3, 4]);
+    }
+
+    #[test]
+    fn mixing_across_blocks() {
+        // Enough numbers to need several blocks, checked against shifting a `Vec`.
+        let numbers: Vec<i64> = (0..50).map(|n| n * 37 % 101 - 50).collect();
+        // The numbers are all different, so they can be looked up by value.
+        let mut expected = numbers.clone();
+        for &number in &numbers {
+            let position = expected.iter().position(|&other| other == number).unwrap();
+            expected.remove(position);
+            let target = (i64::try_from(position).unwrap() + number).rem_euclid(49);
+            expected.insert(usize::try_from(target).unwrap(), number);
+        }
+
+        let mut mixer = Mixer::new(numbers);
+        mixer.mix();
+        let mut actual: Vec<i64> = mixer.mixed().collect();
+        let offset = actual
+            .iter()
+            .position(|&number| number == expected[0])
+            .unwrap();
+        actual.rotate_left(offset);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(INPUT), 3);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(INPUT), 1_623_178_306);
+    }
+}
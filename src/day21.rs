@@ -0,0 +1,155 @@
+use std::{collections::HashMap, str::FromStr};
+
+use itertools::Itertools;
+
+use crate::operator::Operator;
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Job {
+    Number(i64),
+    Operation(String, Operator, String),
+}
+
+impl FromStr for Job {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(number) = s.parse() {
+            return Ok(Job::Number(number));
+        }
+        let (left, operator, right) = s
+            .split_whitespace()
+            .collect_tuple()
+            .ok_or("Expected a number or an operation!")?;
+        Ok(Job::Operation(
+            left.to_owned(),
+            operator.parse().map_err(|()| "Unknown operator!")?,
+            right.to_owned(),
+        ))
+    }
+}
+
+struct Monkeys(HashMap<String, Job>);
+
+impl FromStr for Monkeys {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines()
+            .map(|line| {
+                let (name, job) = line.split_once(": ").ok_or("Missing job!")?;
+                Ok((name.to_owned(), job.parse()?))
+            })
+            .try_collect()
+            .map(Monkeys)
+    }
+}
+
+impl Monkeys {
+    fn evaluate(&self, name: &str) -> i64 {
+        match &self.0[name] {
+            Job::Number(number) => *number,
+            Job::Operation(left, operator, right) => {
+                operator.apply(self.evaluate(left), self.evaluate(right))
+            }
+        }
+    }
+
+    fn depends_on_human(&self, name: &str) -> bool {
+        name == HUMAN
+            || match &self.0[name] {
+                Job::Number(_) => false,
+                Job::Operation(left, _, right) => {
+                    self.depends_on_human(left) || self.depends_on_human(right)
+                }
+            }
+    }
+
+    /// Finds what the human has to yell for `name` to yell `result`,
+    /// by undoing the operations on the way down to the human.
+    fn solve(&self, name: &str, result: i64) -> i64 {
+        if name == HUMAN {
+            return result;
+        }
+        let Job::Operation(left, operator, right) = &self.0[name] else {
+            unreachable!("{name} does not depend on the human");
+        };
+        if self.depends_on_human(left) {
+            self.solve(left, operator.solve_left(result, self.evaluate(right)))
+        } else {
+            self.solve(right, operator.solve_right(result, self.evaluate(left)))
+        }
+    }
+
+    /// The root only checks that both of its operands are equal.
+    fn solve_root(&self) -> i64 {
+        let Job::Operation(left, _, right) = &self.0[ROOT] else {
+            unreachable!("The root has no operands");
+        };
+        if self.depends_on_human(left) {
+            self.solve(left, self.evaluate(right))
+        } else {
+            self.solve(right, self.evaluate(left))
+        }
+    }
+}
+
+pub fn solve_part1(input: &str) -> i64 {
+    input.parse::<Monkeys>().unwrap().evaluate(ROOT)
+}
+
+pub fn solve_part2(input: &str) -> i64 {
+    input.parse::<Monkeys>().unwrap().solve_root()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        day21::{solve_part1, solve_part2, Job},
+        operator::Operator,
+    };
+
+    const INPUT: &str = "root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+
+    #[test]
+    fn parsing() {
+        assert_eq!("5".parse(), Ok(Job::Number(5)));
+        assert_eq!(
+            "pppw + sjmn".parse(),
+            Ok(Job::Operation(
+                "pppw".to_owned(),
+                Operator::Add,
+                "sjmn".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(INPUT), 152);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(INPUT), 301);
+    }
+}
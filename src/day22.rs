@@ -0,0 +1,378 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::geometry::{Coordinate, Coordinate3, Direction};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Void,
+    Open,
+    Wall,
+}
+
+struct Board {
+    buffer: Vec<Tile>,
+    width: usize,
+    height: usize,
+}
+
+impl FromStr for Board {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let width = s.lines().map(str::len).max().ok_or("Empty board!")?;
+        let mut buffer = Vec::new();
+        for line in s.lines() {
+            for c in line.chars() {
+                buffer.push(match c {
+                    ' ' => Tile::Void,
+                    '.' => Tile::Open,
+                    '#' => Tile::Wall,
+                    _ => return Err("Unknown tile!"),
+                });
+            }
+            // Lines stop at their last tile, the rest of the row is void.
+            buffer.resize(buffer.len().next_multiple_of(width), Tile::Void);
+        }
+        Ok(Board {
+            height: buffer.len() / width,
+            buffer,
+            width,
+        })
+    }
+}
+
+impl Board {
+    fn get(&self, coordinate: Coordinate) -> Tile {
+        match (usize::try_from(coordinate.x), usize::try_from(coordinate.y)) {
+            (Ok(x), Ok(y)) if x < self.width && y < self.height => self.buffer[y * self.width + x],
+            _ => Tile::Void,
+        }
+    }
+
+    /// The leftmost open tile of the top row.
+    fn start(&self) -> Coordinate {
+        let x = self.buffer[..self.width]
+            .iter()
+            .position(|&tile| tile == Tile::Open)
+            .unwrap();
+        Coordinate::new(i32::try_from(x).unwrap(), 0)
+    }
+
+    /// Comes back around from the other side of the row or column.
+    fn flat_wrap(&self, position: Coordinate, facing: Direction) -> (Coordinate, Direction) {
+        let back = facing.reverse().offset();
+        let mut wrapped = position;
+        while self.get(wrapped + back) != Tile::Void {
+            wrapped = wrapped + back;
+        }
+        (wrapped, facing)
+    }
+
+    fn walk(
+        &self,
+        (mut position, mut facing): (Coordinate, Direction),
+        path: &[Step],
+        wrap: impl Fn(Coordinate, Direction) -> (Coordinate, Direction),
+    ) -> (Coordinate, Direction) {
+        for step in path {
+            match step {
+                Step::Left => facing = facing.turn_left(),
+                Step::Right => facing = facing.turn_right(),
+                Step::Forward(tiles) => {
+                    for _ in 0..*tiles {
+                        let next = position + facing.offset();
+                        let (next, next_facing) = if self.get(next) == Tile::Void {
+                            wrap(position, facing)
+                        } else {
+                            (next, facing)
+                        };
+                        if self.get(next) == Tile::Wall {
+                            break;
+                        }
+                        (position, facing) = (next, next_facing);
+                    }
+                }
+            }
+        }
+        (position, facing)
+    }
+}
+
+/// One face of the folded cube: which way it faces, and where its
+/// east and south on the board point to on the cube.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    normal: Coordinate3,
+    east: Coordinate3,
+    south: Coordinate3,
+}
+
+impl Face {
+    fn towards(self, direction: Direction) -> Coordinate3 {
+        match direction {
+            Direction::North => -self.south,
+            Direction::East => self.east,
+            Direction::South => self.south,
+            Direction::West => -self.east,
+        }
+    }
+
+    /// The face that is reached by rolling the cube over the edge in `direction`.
+    /// The old face's outside becomes the heading back towards the edge.
+    fn roll(self, direction: Direction) -> Face {
+        let normal = self.towards(direction);
+        match direction {
+            Direction::North => Face {
+                normal,
+                south: self.normal,
+                ..self
+            },
+            Direction::East => Face {
+                normal,
+                east: -self.normal,
+                ..self
+            },
+            Direction::South => Face {
+                normal,
+                south: -self.normal,
+                ..self
+            },
+            Direction::West => Face {
+                normal,
+                east: self.normal,
+                ..self
+            },
+        }
+    }
+}
+
+/// The board folded into a cube, with the faces found from the board itself.
+struct Cube {
+    size: i32,
+    // Keyed by the position of the face on the board, in units of faces.
+    faces: HashMap<Coordinate, Face>,
+}
+
+impl Cube {
+    fn fold(board: &Board) -> Self {
+        let tiles = board
+            .buffer
+            .iter()
+            .filter(|&&tile| tile != Tile::Void)
+            .count();
+        let size = i32::try_from((tiles / 6).isqrt()).unwrap();
+        let first = Coordinate::new(board.start().x / size, 0);
+
+        let mut faces = HashMap::from([(
+            first,
+            Face {
+                normal: Coordinate3::new(0, 0, -1),
+                east: Coordinate3::new(1, 0, 0),
+                south: Coordinate3::new(0, 1, 0),
+            },
+        )]);
+        let mut queue = vec![first];
+        while let Some(net) = queue.pop() {
+            let face = faces[&net];
+            for direction in Direction::DIRECTIONS {
+                let neighbour = net + direction.offset();
+                let corner = Coordinate::new(neighbour.x * size, neighbour.y * size);
+                if board.get(corner) != Tile::Void && !faces.contains_key(&neighbour) {
+                    faces.insert(neighbour, face.roll(direction));
+                    queue.push(neighbour);
+                }
+            }
+        }
+        Cube { size, faces }
+    }
+
+    /// Walks over the edge of a face onto the face next to it on the cube.
+    ///
+    /// Works on doubled coordinates centred on the cube, so that the centre of every tile is a
+    /// whole point and the cube spans from `-size` to `size` on every axis.
+    fn wrap(&self, position: Coordinate, facing: Direction) -> (Coordinate, Direction) {
+        let size = self.size;
+        let net = Coordinate::new(position.x / size, position.y / size);
+        let face = self.faces[&net];
+        let local = position - Coordinate::new(net.x * size, net.y * size);
+        let point = face.normal * size
+            + face.east * (2 * local.x + 1 - size)
+            + face.south * (2 * local.y + 1 - size);
+
+        // Half a tile over the edge, then half a tile down the side of the cube.
+        let heading = face.towards(facing);
+        let point = point + heading - face.normal;
+        let (&net, &target) = self
+            .faces
+            .iter()
+            .find(|(_, other)| other.normal == heading)
+            .unwrap();
+        let local = Coordinate::new(
+            (point.dot(target.east) + size - 1) / 2,
+            (point.dot(target.south) + size - 1) / 2,
+        );
+        let facing = Direction::DIRECTIONS
+            .into_iter()
+            .find(|&direction| target.towards(direction) == -face.normal)
+            .unwrap();
+        (Coordinate::new(net.x * size, net.y * size) + local, facing)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Forward(u32),
+    Left,
+    Right,
+}
+
+fn parse_path(s: &str) -> Vec<Step> {
+    let mut path = Vec::new();
+    let mut tiles = None;
+    for c in s.trim().chars() {
+        if let Some(digit) = c.to_digit(10) {
+            tiles = Some(tiles.unwrap_or(0) * 10 + digit);
+            continue;
+        }
+        path.extend(tiles.take().map(Step::Forward));
+        path.push(match c {
+            'L' => Step::Left,
+            'R' => Step::Right,
+            _ => unreachable!(),
+        });
+    }
+    path.extend(tiles.map(Step::Forward));
+    path
+}
+
+fn parse(input: &str) -> (Board, Vec<Step>) {
+    let (board, path) = input.split_once("\n\n").unwrap();
+    (board.parse().unwrap(), parse_path(path))
+}
+
+fn password((position, facing): (Coordinate, Direction)) -> i32 {
+    let facing = match facing {
+        Direction::East => 0,
+        Direction::South => 1,
+        Direction::West => 2,
+        Direction::North => 3,
+    };
+    1000 * (position.y + 1) + 4 * (position.x + 1) + facing
+}
+
+pub fn solve_part1(input: &str) -> i32 {
+    let (board, path) = parse(input);
+    password(board.walk(
+        (board.start(), Direction::East),
+        &path,
+        |position, facing| board.flat_wrap(position, facing),
+    ))
+}
+
+pub fn solve_part2(input: &str) -> i32 {
+    let (board, path) = parse(input);
+    let cube = Cube::fold(&board);
+    password(board.walk(
+        (board.start(), Direction::East),
+        &path,
+        |position, facing| cube.wrap(position, facing),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        day22::{parse, parse_path, solve_part1, solve_part2, Board, Cube, Step, Tile},
+        geometry::{Coordinate, Direction},
+    };
+
+    const INPUT: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5";
+
+    /// Blows every face of `layout` up into an open square of `size` tiles.
+    fn net(layout: &str, size: usize) -> Board {
+        layout
+            .lines()
+            .flat_map(|line| {
+                let row: String = line
+                    .chars()
+                    .map(|c| {
+                        if c == '#' {
+                            ".".repeat(size)
+                        } else {
+                            " ".repeat(size)
+                        }
+                    })
+                    .collect();
+                std::iter::repeat_n(row, size)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn parsing() {
+        let (board, path) = parse(INPUT);
+        assert_eq!((board.width, board.height), (16, 12));
+        assert_eq!(board.get(Coordinate::new(11, 0)), Tile::Wall);
+        assert_eq!(board.get(Coordinate::new(12, 0)), Tile::Void);
+        assert_eq!(board.start(), Coordinate::new(8, 0));
+        assert_eq!(
+            path[..3],
+            [Step::Forward(10), Step::Right, Step::Forward(5)]
+        );
+    }
+
+    #[test]
+    fn around_the_cube() {
+        // Walking straight ahead all the way around the cube ends up where it started.
+        for (layout, size) in [("  #\n###\n  ##", 4), (" ##\n #\n##\n#", 3)] {
+            let board = net(layout, size);
+            let cube = Cube::fold(&board);
+            assert_eq!(cube.faces.len(), 6);
+            let around = parse_path(&(4 * size).to_string());
+            let width = i32::try_from(board.width).unwrap();
+            let height = i32::try_from(board.height).unwrap();
+            for y in 0..height {
+                for x in 0..width {
+                    let position = Coordinate::new(x, y);
+                    if board.get(position) == Tile::Void {
+                        continue;
+                    }
+                    for facing in Direction::DIRECTIONS {
+                        let walked = board.walk((position, facing), &around, |position, facing| {
+                            cube.wrap(position, facing)
+                        });
+                        assert_eq!(walked, (position, facing));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(INPUT), 6032);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(INPUT), 5031);
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    geometry::{Coordinate, Direction},
+    simulation::Simulation,
+};
+
+/// The order in which the elves consider moving during the first round.
+const PROPOSALS: [Direction; 4] = [
+    Direction::North,
+    Direction::South,
+    Direction::West,
+    Direction::East,
+];
+
+#[derive(Debug, Clone)]
+struct Grove {
+    elves: HashSet<Coordinate>,
+    rounds: usize,
+    settled: bool,
+}
+
+impl FromStr for Grove {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut elves = HashSet::new();
+        for (y, line) in (0..).zip(s.lines()) {
+            for (x, c) in (0..).zip(line.chars()) {
+                match c {
+                    '#' => {
+                        elves.insert(Coordinate::new(x, y));
+                    }
+                    '.' => {}
+                    _ => return Err("Unknown tile!"),
+                }
+            }
+        }
+        Ok(Grove {
+            elves,
+            rounds: 0,
+            settled: false,
+        })
+    }
+}
+
+impl fmt::Display for Grove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min, max) = self.bounds();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                if self.elves.contains(&Coordinate::new(x, y)) {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            if y != max.y {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Grove {
+    fn bounds(&self) -> (Coordinate, Coordinate) {
+        let (min_x, max_x) = self
+            .elves
+            .iter()
+            .map(|elf| elf.x)
+            .minmax()
+            .into_option()
+            .unwrap_or_default();
+        let (min_y, max_y) = self
+            .elves
+            .iter()
+            .map(|elf| elf.y)
+            .minmax()
+            .into_option()
+            .unwrap_or_default();
+        (Coordinate::new(min_x, min_y), Coordinate::new(max_x, max_y))
+    }
+
+    fn empty_ground(&self) -> usize {
+        let (min, max) = self.bounds();
+        let area = (max.x - min.x + 1) * (max.y - min.y + 1);
+        usize::try_from(area).unwrap() - self.elves.len()
+    }
+
+    fn is_free(&self, elf: Coordinate, direction: Direction) -> bool {
+        let ahead = elf + direction.offset();
+        [
+            ahead,
+            ahead + direction.turn_left().offset(),
+            ahead + direction.turn_right().offset(),
+        ]
+        .iter()
+        .all(|neighbour| !self.elves.contains(neighbour))
+    }
+
+    fn propose(&self, elf: Coordinate) -> Option<Coordinate> {
+        let free = PROPOSALS.map(|direction| self.is_free(elf, direction));
+        if free.iter().all(|&free| free) {
+            return None;
+        }
+        (0..PROPOSALS.len())
+            .map(|offset| (self.rounds + offset) % PROPOSALS.len())
+            .find(|&proposal| free[proposal])
+            .map(|proposal| elf + PROPOSALS[proposal].offset())
+    }
+}
+
+/// Runs a single round per step, until no elf moves.
+impl Simulation for Grove {
+    type Snapshot = Grove;
+
+    fn step(&mut self) {
+        // Each target, along with the elf that proposed it, unless several did.
+        let mut proposals: HashMap<Coordinate, Option<Coordinate>> = HashMap::new();
+        for &elf in &self.elves {
+            if let Some(target) = self.propose(elf) {
+                proposals
+                    .entry(target)
+                    .and_modify(|proposer| *proposer = None)
+                    .or_insert(Some(elf));
+            }
+        }
+
+        let mut moved = false;
+        for (target, proposer) in proposals {
+            if let Some(elf) = proposer {
+                self.elves.remove(&elf);
+                self.elves.insert(target);
+                moved = true;
+            }
+        }
+        self.rounds += 1;
+        self.settled = !moved;
+    }
+
+    fn is_done(&self) -> bool {
+        self.settled
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.clone()
+    }
+
+    fn restore(&mut self, snapshot: Self::Snapshot) {
+        *self = snapshot;
+    }
+}
+
+fn spread(input: &str, rounds: usize) -> Grove {
+    let mut grove: Grove = input.parse().unwrap();
+    while grove.rounds < rounds && !grove.is_done() {
+        grove.step();
+    }
+    grove
+}
+
+/// Draws the elves after the ten rounds of part 1.
+pub fn render(input: &str) -> String {
+    spread(input, 10).to_string()
+}
+
+pub fn solve_part1(input: &str) -> usize {
+    spread(input, 10).empty_ground()
+}
+
+pub fn solve_part2(input: &str) -> usize {
+    let mut grove: Grove = input.parse().unwrap();
+    grove.run_to_end();
+    grove.rounds
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        day23::{render, solve_part1, solve_part2, Grove},
+        simulation::Recorder,
+    };
+
+    const INPUT: &str = "....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..";
+
+    #[test]
+    fn small_example() {
+        let small = ".....
+..##.
+..#..
+.....
+..##.
+.....";
+        let mut recorder = Recorder::new(small.parse::<Grove>().unwrap(), 4);
+        recorder.step();
+        assert_eq!(recorder.simulation().to_string(), "##\n..\n#.\n.#\n#.");
+        recorder.run_to_end();
+        assert_eq!(recorder.steps(), 4);
+        assert_eq!(
+            recorder.simulation().to_string(),
+            "..#..\n....#\n#....\n....#\n.....\n..#.."
+        );
+        assert!(recorder.rewind_to(1));
+        assert_eq!(recorder.simulation().rounds, 1);
+    }
+
+    #[test]
+    fn rendering() {
+        assert_eq!(
+            render(INPUT),
+            "......#.....
+..........#.
+.#.#..#.....
+.....#......
+..#.....#..#
+#......##...
+....##......
+.#........#.
+...#.#..#...
+............
+...#..#..#.."
+        );
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(INPUT), 110);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(INPUT), 20);
+    }
+}
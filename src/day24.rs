@@ -0,0 +1,166 @@
+use std::str::FromStr;
+
+use pathfinding::prelude::bfs;
+
+use crate::{
+    geometry::{Coordinate, Direction},
+    math::lcm,
+};
+
+/// The valley inside its walls, where the top left open tile is at the origin
+/// and the entrance and exit lie just outside the first and last rows.
+struct Valley {
+    width: i32,
+    height: i32,
+    // For every minute of one period, which tiles are covered by a blizzard.
+    blizzards: Vec<Vec<bool>>,
+}
+
+impl FromStr for Valley {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<&str> = s.lines().collect();
+        let inner = rows
+            .get(1..rows.len().saturating_sub(1))
+            .ok_or("Missing walls!")?;
+        let height = i32::try_from(inner.len()).map_err(|_| "Valley too high!")?;
+        let width =
+            i32::try_from(rows[0].len().saturating_sub(2)).map_err(|_| "Valley too wide!")?;
+
+        let mut winds = Vec::new();
+        for (y, row) in (0..).zip(inner) {
+            let row = row
+                .strip_prefix('#')
+                .and_then(|row| row.strip_suffix('#'))
+                .ok_or("Missing walls!")?;
+            for (x, c) in (0..).zip(row.chars()) {
+                let direction = match c {
+                    '^' => Direction::North,
+                    '>' => Direction::East,
+                    'v' => Direction::South,
+                    '<' => Direction::West,
+                    '.' => continue,
+                    _ => return Err("Unknown tile!"),
+                };
+                winds.push((Coordinate::new(x, y), direction));
+            }
+        }
+
+        // Every blizzard is back where it started after a multiple of both sides.
+        let period = lcm(&[
+            u64::from(width.unsigned_abs()),
+            u64::from(height.unsigned_abs()),
+        ]);
+        let blizzards = (0..i32::try_from(period).map_err(|_| "Valley too large!")?)
+            .map(|minute| {
+                let mut covered = vec![false; inner.len() * usize::try_from(width).unwrap()];
+                for &(start, direction) in &winds {
+                    let offset = direction.offset();
+                    let x = (start.x + offset.x * minute).rem_euclid(width);
+                    let y = (start.y + offset.y * minute).rem_euclid(height);
+                    covered[usize::try_from(y * width + x).unwrap()] = true;
+                }
+                covered
+            })
+            .collect();
+
+        Ok(Valley {
+            width,
+            height,
+            blizzards,
+        })
+    }
+}
+
+impl Valley {
+    const ENTRANCE: Coordinate = Coordinate::new(0, -1);
+
+    fn exit(&self) -> Coordinate {
+        Coordinate::new(self.width - 1, self.height)
+    }
+
+    fn is_free(&self, position: Coordinate, minute: usize) -> bool {
+        if position == Self::ENTRANCE || position == self.exit() {
+            return true;
+        }
+        if !(0..self.width).contains(&position.x) || !(0..self.height).contains(&position.y) {
+            return false;
+        }
+        let index = usize::try_from(position.y * self.width + position.x).unwrap();
+        !self.blizzards[minute % self.blizzards.len()][index]
+    }
+
+    /// The minute at which the expedition can first reach `to`, setting off at `minute`.
+    /// Only the minute within one period of the blizzards tells states apart.
+    fn trip(&self, from: Coordinate, to: Coordinate, minute: usize) -> usize {
+        let period = self.blizzards.len();
+        let path = bfs(
+            &(from, minute % period),
+            |&(position, minute)| {
+                let next = (minute + 1) % period;
+                Direction::DIRECTIONS
+                    .map(|direction| position + direction.offset())
+                    .into_iter()
+                    .chain([position])
+                    .filter(move |&position| self.is_free(position, next))
+                    .map(move |position| (position, next))
+                    .collect::<Vec<_>>()
+            },
+            |&(position, _)| position == to,
+        )
+        .unwrap();
+        minute + path.len() - 1
+    }
+}
+
+pub fn solve_part1(input: &str) -> usize {
+    let valley: Valley = input.parse().unwrap();
+    valley.trip(Valley::ENTRANCE, valley.exit(), 0)
+}
+
+pub fn solve_part2(input: &str) -> usize {
+    let valley: Valley = input.parse().unwrap();
+    let there = valley.trip(Valley::ENTRANCE, valley.exit(), 0);
+    let back = valley.trip(valley.exit(), Valley::ENTRANCE, there);
+    valley.trip(Valley::ENTRANCE, valley.exit(), back)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        day24::{solve_part1, solve_part2, Valley},
+        geometry::Coordinate,
+    };
+
+    const INPUT: &str = "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+
+    #[test]
+    fn periodic() {
+        let valley: Valley = INPUT.parse().unwrap();
+        assert_eq!((valley.width, valley.height), (6, 4));
+        assert_eq!(valley.blizzards.len(), 12);
+        assert!(!valley.is_free(Coordinate::new(0, 0), 0));
+        assert!(valley.is_free(Coordinate::new(0, 0), 1));
+        assert!(!valley.is_free(Coordinate::new(0, 0), 12));
+        assert!(valley.is_free(Valley::ENTRANCE, 0));
+        assert!(!valley.is_free(Coordinate::new(1, -1), 0));
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(INPUT), 18);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(INPUT), 54);
+    }
+}
@@ -0,0 +1,216 @@
+use std::{fmt, iter::Sum, ops::Add, str::FromStr};
+
+/// A balanced base-5 number of any size.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snafu {
+    // Least significant first, without leading zeros, so zero has no digits at all.
+    digits: Vec<i8>,
+}
+
+impl Snafu {
+    /// Splits `value` into a digit from -2 to 2 and what carries over to the next place.
+    fn balance(value: i8) -> (i8, i8) {
+        let digit = (value + 2).rem_euclid(5) - 2;
+        (digit, (value - digit) / 5)
+    }
+
+    fn trim(mut self) -> Self {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+        self
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err("Empty SNAFU number!");
+        }
+        let digits = s
+            .chars()
+            .rev()
+            .map(|c| match c {
+                '2' => Ok(2),
+                '1' => Ok(1),
+                '0' => Ok(0),
+                '-' => Ok(-1),
+                '=' => Ok(-2),
+                _ => Err("Unknown SNAFU digit!"),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Snafu { digits }.trim())
+    }
+}
+
+impl fmt::Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.digits.is_empty() {
+            return write!(f, "0");
+        }
+        for digit in self.digits.iter().rev() {
+            let c = match digit {
+                2 => '2',
+                1 => '1',
+                0 => '0',
+                -1 => '-',
+                -2 => '=',
+                _ => unreachable!(),
+            };
+            write!(f, "{c}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Add<&Snafu> for Snafu {
+    type Output = Snafu;
+
+    fn add(self, rhs: &Snafu) -> Self::Output {
+        let places = self.digits.len().max(rhs.digits.len());
+        let mut digits = Vec::with_capacity(places + 1);
+        let mut carry = 0;
+        for place in 0..places {
+            let left = self.digits.get(place).unwrap_or(&0);
+            let right = rhs.digits.get(place).unwrap_or(&0);
+            let (digit, next) = Snafu::balance(left + right + carry);
+            digits.push(digit);
+            carry = next;
+        }
+        digits.push(carry);
+        Snafu { digits }.trim()
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Snafu>>(iter: I) -> Self {
+        iter.fold(Snafu::default(), |sum, number| sum + number)
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(mut value: i64) -> Self {
+        let mut digits = Vec::new();
+        while value != 0 {
+            let (digit, carry) = Snafu::balance(i8::try_from(value.rem_euclid(5)).unwrap());
+            digits.push(digit);
+            value = value.div_euclid(5) + i64::from(carry);
+        }
+        Snafu { digits }
+    }
+}
+
+impl TryFrom<&Snafu> for i64 {
+    type Error = &'static str;
+
+    fn try_from(value: &Snafu) -> Result<Self, Self::Error> {
+        // A wider accumulator, as the digits can overshoot the result before coming back.
+        let decimal = value
+            .digits
+            .iter()
+            .rev()
+            .try_fold(0_i128, |decimal, &digit| {
+                decimal
+                    .checked_mul(5)
+                    .and_then(|decimal| decimal.checked_add(digit.into()))
+            });
+        decimal
+            .and_then(|decimal| i64::try_from(decimal).ok())
+            .ok_or("SNAFU number does not fit!")
+    }
+}
+
+fn parse(input: &str) -> Vec<Snafu> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+pub fn solve_part1(input: &str) -> Snafu {
+    parse(input).iter().sum()
+}
+
+/// There is no second puzzle on the last day.
+pub fn solve_part2(_input: &str) -> &'static str {
+    "Start the blender!"
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use quickcheck::quickcheck;
+
+    use crate::day25::{solve_part1, Snafu};
+
+    const INPUT: &str = "1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122";
+
+    #[test]
+    fn conversions() {
+        for (decimal, snafu) in [
+            (0, "0"),
+            (1, "1"),
+            (3, "1="),
+            (8, "2="),
+            (10, "20"),
+            (2022, "1=11-2"),
+            (12345, "1-0---0"),
+            (314_159_265, "1121-1110-1=0"),
+        ] {
+            assert_eq!(Snafu::from(decimal).to_string(), snafu);
+            assert_eq!(i64::try_from(&snafu.parse::<Snafu>().unwrap()), Ok(decimal));
+        }
+        assert_eq!("00".parse(), Ok(Snafu::default()));
+        assert!("1+".parse::<Snafu>().is_err());
+    }
+
+    #[test]
+    fn arbitrary_precision() {
+        let max = Snafu::from(i64::MAX);
+        let sum: Snafu = [&max, &max, &max].into_iter().sum();
+        // 3 × (2⁶³ - 1).
+        assert_eq!(sum.to_string(), "1--=-11=2100=0-=--2-0=20--2-1");
+        assert!(i64::try_from(&sum).is_err());
+        for extreme in [i64::MIN, i64::MAX] {
+            assert_eq!(i64::try_from(&Snafu::from(extreme)), Ok(extreme));
+        }
+        assert_eq!(
+            i64::try_from(&(sum + &Snafu::from(-i64::MAX) + &Snafu::from(-i64::MAX))),
+            Ok(i64::MAX)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        fn decimal(value: i64) -> bool {
+            i64::try_from(&Snafu::from(value)) == Ok(value)
+        }
+        fn text(value: i64) -> bool {
+            let snafu = Snafu::from(value);
+            snafu.to_string().parse() == Ok(snafu)
+        }
+        fn addition(a: i32, b: i32) -> bool {
+            let sum = Snafu::from(i64::from(a)) + &Snafu::from(i64::from(b));
+            i64::try_from(&sum) == Ok(i64::from(a) + i64::from(b))
+        }
+        quickcheck(decimal as fn(i64) -> bool);
+        quickcheck(text as fn(i64) -> bool);
+        quickcheck(addition as fn(i32, i32) -> bool);
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(INPUT).to_string(), "2=-1=0");
+    }
+}
@@ -0,0 +1,170 @@
+//! C bindings for the solvers. The matching header lives in `include/aoc2022.h`.
+
+use std::{
+    ffi::{c_char, CString},
+    ptr, slice, str,
+};
+
+use crate::registry::{self, Part};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    Ok = 0,
+    NullInput = 1,
+    UnknownDay = 2,
+    InvalidPart = 3,
+    InvalidUtf8 = 4,
+    SolverPanicked = 5,
+}
+
+/// Exactly one of `answer` and `message` is set, depending on `status`.
+/// Both are owned by the result and released by [`aoc_result_free`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct AocResult {
+    pub status: AocStatus,
+    pub answer: *mut c_char,
+    pub message: *mut c_char,
+}
+
+fn into_c_string(s: &str) -> *mut c_char {
+    CString::new(s.replace('\0', ""))
+        .expect("interior nul bytes were removed")
+        .into_raw()
+}
+
+impl AocResult {
+    fn answer(answer: &str) -> Self {
+        Self {
+            status: AocStatus::Ok,
+            answer: into_c_string(answer),
+            message: ptr::null_mut(),
+        }
+    }
+
+    fn error(status: AocStatus, message: &str) -> Self {
+        Self {
+            status,
+            answer: ptr::null_mut(),
+            message: into_c_string(message),
+        }
+    }
+}
+
+fn solve(day: u32, part: u32, input: &[u8]) -> AocResult {
+    let Some(day) = registry::get(day) else {
+        return AocResult::error(AocStatus::UnknownDay, &format!("day {day} is not solved"));
+    };
+    let Ok(part) = Part::try_from(part) else {
+        return AocResult::error(
+            AocStatus::InvalidPart,
+            &format!("part {part} does not exist, expected 1 or 2"),
+        );
+    };
+    let input = match str::from_utf8(input) {
+        Ok(input) => input,
+        Err(error) => return AocResult::error(AocStatus::InvalidUtf8, &error.to_string()),
+    };
+    // Panics must not unwind into the caller.
+    match day.try_solve(part, input) {
+        Ok(answer) => AocResult::answer(&answer),
+        Err(message) => AocResult::error(AocStatus::SolverPanicked, &message),
+    }
+}
+
+/// Solves `part` (1 or 2) of `day` for the `input_len` bytes at `input`.
+///
+/// The returned result is never null and must be released with [`aoc_result_free`].
+///
+/// # Safety
+///
+/// `input` must either be null or point to `input_len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u32,
+    part: u32,
+    input: *const c_char,
+    input_len: usize,
+) -> *mut AocResult {
+    let result = if input.is_null() {
+        AocResult::error(AocStatus::NullInput, "input is null")
+    } else {
+        solve(
+            day,
+            part,
+            slice::from_raw_parts(input.cast::<u8>(), input_len),
+        )
+    };
+    Box::into_raw(Box::new(result))
+}
+
+/// Releases a result returned by [`aoc_solve`]. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `result` must be null or a pointer returned by [`aoc_solve`] that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_result_free(result: *mut AocResult) {
+    if result.is_null() {
+        return;
+    }
+    let result = Box::from_raw(result);
+    for string in [result.answer, result.message] {
+        if !string.is_null() {
+            drop(CString::from_raw(string));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "day02"))]
+mod tests {
+    use std::ffi::{c_char, CStr};
+
+    use crate::ffi::{aoc_result_free, aoc_solve, AocStatus};
+
+    fn run(day: u32, part: u32, input: &[u8]) -> (AocStatus, Option<String>, Option<String>) {
+        let to_string = |string: *mut c_char| {
+            (!string.is_null()).then(|| {
+                unsafe { CStr::from_ptr(string) }
+                    .to_string_lossy()
+                    .into_owned()
+            })
+        };
+        unsafe {
+            let result = aoc_solve(day, part, input.as_ptr().cast(), input.len());
+            let output = (
+                (*result).status,
+                to_string((*result).answer),
+                to_string((*result).message),
+            );
+            aoc_result_free(result);
+            output
+        }
+    }
+
+    #[test]
+    fn answers() {
+        assert_eq!(
+            run(2, 1, b"A Y\nB X\nC Z"),
+            (AocStatus::Ok, Some("15".to_owned()), None)
+        );
+        assert_eq!(
+            run(2, 2, b"A Y\nB X\nC Z"),
+            (AocStatus::Ok, Some("12".to_owned()), None)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(run(99, 1, b"").0, AocStatus::UnknownDay);
+        assert_eq!(run(2, 3, b"").0, AocStatus::InvalidPart);
+        assert_eq!(run(2, 1, b"\xff").0, AocStatus::InvalidUtf8);
+        assert_eq!(run(2, 1, b"A").0, AocStatus::SolverPanicked);
+        unsafe {
+            let result = aoc_solve(2, 1, std::ptr::null(), 0);
+            assert_eq!((*result).status, AocStatus::NullInput);
+            aoc_result_free(result);
+        }
+    }
+}
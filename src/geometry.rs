@@ -0,0 +1,302 @@
+//! Grid geometry shared between days.
+//!
+//! Each day only uses part of it, so every item is exempt from the dead code lint
+//! unless one of the days that use it is enabled.
+
+use std::{
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    not(any(
+        feature = "day14",
+        feature = "day15",
+        feature = "day22",
+        feature = "day23",
+        feature = "day24"
+    )),
+    allow(dead_code)
+)]
+pub(crate) struct Coordinate {
+    pub(crate) x: i32,
+    pub(crate) y: i32,
+}
+
+impl fmt::Debug for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("").field(&self.x).field(&self.y).finish()
+    }
+}
+
+impl Add<Coordinate> for Coordinate {
+    type Output = Coordinate;
+
+    fn add(self, rhs: Coordinate) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Sub<Coordinate> for Coordinate {
+    type Output = Coordinate;
+
+    fn sub(self, rhs: Coordinate) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl Coordinate {
+    #[cfg_attr(
+        not(any(
+            feature = "day15",
+            feature = "day22",
+            feature = "day23",
+            feature = "day24"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    #[cfg_attr(not(feature = "day15"), allow(dead_code))]
+    pub(crate) fn manhattan_distance(self, other: Coordinate) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+/// Parses `x,y`.
+impl FromStr for Coordinate {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.split_once(',').ok_or(())?;
+        Ok(Coordinate {
+            x: x.trim().parse().map_err(|_| ())?,
+            y: y.trim().parse().map_err(|_| ())?,
+        })
+    }
+}
+
+/// A heading on the grid, where `y` grows towards the south.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    not(any(feature = "day22", feature = "day23", feature = "day24")),
+    allow(dead_code)
+)]
+pub(crate) enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// In clockwise order.
+    #[cfg_attr(not(any(feature = "day22", feature = "day24")), allow(dead_code))]
+    pub(crate) const DIRECTIONS: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    #[cfg_attr(not(any(feature = "day22", feature = "day23")), allow(dead_code))]
+    pub(crate) const fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    #[cfg_attr(not(any(feature = "day22", feature = "day23")), allow(dead_code))]
+    pub(crate) const fn turn_left(self) -> Direction {
+        self.reverse().turn_right()
+    }
+
+    #[cfg_attr(not(any(feature = "day22", feature = "day23")), allow(dead_code))]
+    pub(crate) const fn reverse(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    #[cfg_attr(
+        not(any(feature = "day22", feature = "day23", feature = "day24")),
+        allow(dead_code)
+    )]
+    pub(crate) const fn offset(self) -> Coordinate {
+        match self {
+            Direction::North => Coordinate::new(0, -1),
+            Direction::East => Coordinate::new(1, 0),
+            Direction::South => Coordinate::new(0, 1),
+            Direction::West => Coordinate::new(-1, 0),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(not(any(feature = "day18", feature = "day22")), allow(dead_code))]
+pub(crate) struct Coordinate3 {
+    pub(crate) x: i32,
+    pub(crate) y: i32,
+    pub(crate) z: i32,
+}
+
+impl fmt::Debug for Coordinate3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .finish()
+    }
+}
+
+impl Add<Coordinate3> for Coordinate3 {
+    type Output = Coordinate3;
+
+    fn add(self, rhs: Coordinate3) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl Sub<Coordinate3> for Coordinate3 {
+    type Output = Coordinate3;
+
+    fn sub(self, rhs: Coordinate3) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl Neg for Coordinate3 {
+    type Output = Coordinate3;
+
+    fn neg(self) -> Self::Output {
+        Coordinate3::default() - self
+    }
+}
+
+impl Mul<i32> for Coordinate3 {
+    type Output = Coordinate3;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl Coordinate3 {
+    /// The unit steps towards each of the six faces of a cube.
+    #[cfg_attr(not(feature = "day18"), allow(dead_code))]
+    pub(crate) const FACES: [Coordinate3; 6] = [
+        Coordinate3::new(1, 0, 0),
+        Coordinate3::new(-1, 0, 0),
+        Coordinate3::new(0, 1, 0),
+        Coordinate3::new(0, -1, 0),
+        Coordinate3::new(0, 0, 1),
+        Coordinate3::new(0, 0, -1),
+    ];
+
+    #[cfg_attr(not(any(feature = "day18", feature = "day22")), allow(dead_code))]
+    pub(crate) const fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    #[cfg_attr(not(feature = "day22"), allow(dead_code))]
+    pub(crate) fn dot(self, other: Coordinate3) -> i32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    #[cfg_attr(not(feature = "day18"), allow(dead_code))]
+    pub(crate) fn neighbours(self) -> impl Iterator<Item = Coordinate3> {
+        Self::FACES.into_iter().map(move |face| self + face)
+    }
+}
+
+/// Parses `x,y,z`.
+impl FromStr for Coordinate3 {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut axes = s.split(',').map(|axis| axis.trim().parse().map_err(|_| ()));
+        let coordinate = Coordinate3 {
+            x: axes.next().ok_or(())??,
+            y: axes.next().ok_or(())??,
+            z: axes.next().ok_or(())??,
+        };
+        match axes.next() {
+            Some(_) => Err(()),
+            None => Ok(coordinate),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Coordinate, Coordinate3, Direction};
+
+    #[test]
+    fn manhattan_distance() {
+        let a = Coordinate::new(8, 7);
+        let b = Coordinate::new(2, 10);
+        assert_eq!(a.manhattan_distance(b), 9);
+        assert_eq!(b.manhattan_distance(a), 9);
+        assert_eq!(a - b + b, a);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("498,4".parse(), Ok(Coordinate::new(498, 4)));
+        assert_eq!("-3, 12".parse(), Ok(Coordinate::new(-3, 12)));
+        assert_eq!("498".parse::<Coordinate>(), Err(()));
+    }
+
+    #[test]
+    fn turning() {
+        for direction in Direction::DIRECTIONS {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(
+                direction.offset() + direction.reverse().offset(),
+                Coordinate::default()
+            );
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+    }
+
+    #[test]
+    fn three_dimensions() {
+        let a = Coordinate3::new(2, 2, 2);
+        assert_eq!("2,2,2".parse(), Ok(a));
+        assert_eq!("1,2".parse::<Coordinate3>(), Err(()));
+        assert_eq!("1,2,3,4".parse::<Coordinate3>(), Err(()));
+        assert!(a.neighbours().all(|neighbour| {
+            let step = neighbour - a;
+            step.x.abs() + step.y.abs() + step.z.abs() == 1
+        }));
+        assert_eq!(a.neighbours().count(), 6);
+        assert_eq!(-a * 2 + a, -a);
+        assert_eq!(a.dot(Coordinate3::new(1, -1, 3)), 6);
+    }
+}
@@ -0,0 +1,76 @@
+//! Named operation counters for performance work.
+//!
+//! Counting is compiled out unless the `instrument` feature is enabled,
+//! in which case [`measure`] reports what was counted while running a solver.
+
+/// Counter names with their totals, sorted by name.
+pub type Counters = Vec<(&'static str, u64)>;
+
+#[cfg(feature = "instrument")]
+mod counters {
+    use std::{cell::RefCell, collections::BTreeMap};
+
+    thread_local! {
+        static COUNTERS: RefCell<BTreeMap<&'static str, u64>> = RefCell::default();
+    }
+
+    pub(super) fn add(name: &'static str, amount: u64) {
+        COUNTERS.with(|counters| *counters.borrow_mut().entry(name).or_default() += amount);
+    }
+
+    pub(super) fn take() -> super::Counters {
+        COUNTERS.with(|counters| counters.take().into_iter().collect())
+    }
+}
+
+#[cfg(not(feature = "instrument"))]
+mod counters {
+    #[allow(dead_code)]
+    #[inline(always)]
+    pub(super) fn add(_name: &'static str, _amount: u64) {}
+
+    pub(super) fn take() -> super::Counters {
+        Vec::new()
+    }
+}
+
+// Only some days are instrumented, and they can all be disabled.
+#[allow(dead_code)]
+#[inline]
+pub(crate) fn add(name: &'static str, amount: u64) {
+    counters::add(name, amount);
+}
+
+#[allow(dead_code)]
+#[inline]
+pub(crate) fn count(name: &'static str) {
+    counters::add(name, 1);
+}
+
+/// Runs `f`, returning its result along with the counters it incremented on this thread.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Counters) {
+    counters::take();
+    let result = f();
+    (result, counters::take())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::instrument::{add, count, measure};
+
+    #[test]
+    fn measure_counters() {
+        count("stray");
+        let ((), counters) = measure(|| {
+            count("b");
+            count("a");
+            add("b", 2);
+        });
+        if cfg!(feature = "instrument") {
+            assert_eq!(counters, [("a", 1), ("b", 3)]);
+        } else {
+            assert!(counters.is_empty());
+        }
+        assert!(measure(|| ()).1.is_empty());
+    }
+}
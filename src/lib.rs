@@ -0,0 +1,71 @@
+#![warn(clippy::pedantic)]
+
+#[cfg(feature = "day01")]
+mod day01;
+#[cfg(feature = "day02")]
+mod day02;
+#[cfg(feature = "day03")]
+mod day03;
+#[cfg(feature = "day04")]
+mod day04;
+#[cfg(feature = "day05")]
+mod day05;
+#[cfg(feature = "day06")]
+mod day06;
+#[cfg(feature = "day07")]
+mod day07;
+#[cfg(feature = "day08")]
+mod day08;
+#[cfg(feature = "day09")]
+mod day09;
+#[cfg(feature = "day10")]
+mod day10;
+#[cfg(feature = "day11")]
+mod day11;
+#[cfg(feature = "day12")]
+mod day12;
+#[cfg(feature = "day13")]
+mod day13;
+#[cfg(feature = "day14")]
+mod day14;
+#[cfg(feature = "day15")]
+mod day15;
+#[cfg(feature = "day16")]
+mod day16;
+#[cfg(feature = "day17")]
+mod day17;
+#[cfg(feature = "day18")]
+mod day18;
+#[cfg(feature = "day19")]
+mod day19;
+#[cfg(feature = "day20")]
+mod day20;
+#[cfg(feature = "day21")]
+mod day21;
+#[cfg(feature = "day22")]
+mod day22;
+#[cfg(feature = "day23")]
+mod day23;
+#[cfg(feature = "day24")]
+mod day24;
+#[cfg(feature = "day25")]
+mod day25;
+
+#[cfg(any(
+    feature = "day14",
+    feature = "day15",
+    feature = "day18",
+    feature = "day22",
+    feature = "day23",
+    feature = "day24"
+))]
+mod geometry;
+#[cfg(any(feature = "day11", feature = "day24"))]
+mod math;
+#[cfg(any(feature = "day11", feature = "day21"))]
+mod operator;
+
+pub mod ffi;
+pub mod instrument;
+pub mod registry;
+pub mod simulation;
@@ -0,0 +1,31 @@
+//! Number theory shared between days.
+
+pub(crate) fn lcm(numbers: &[u64]) -> u64 {
+    let mut lcm = 1;
+    for &number in numbers {
+        lcm = lcm * number / gcd(lcm, number);
+    }
+    lcm
+}
+
+pub(crate) fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{gcd, lcm};
+
+    #[test]
+    fn lcm_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(&[4, 6]), 12);
+        assert_eq!(lcm(&[23, 19, 13, 17]), 96_577);
+        assert_eq!(lcm(&[]), 1);
+    }
+}
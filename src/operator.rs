@@ -0,0 +1,92 @@
+//! Arithmetic operators shared between days.
+
+use std::{
+    ops::{Add, Div, Mul, Sub},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// Parses `+`, `-`, `*` or `/`.
+impl FromStr for Operator {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Operator::Add),
+            "-" => Ok(Operator::Sub),
+            "*" => Ok(Operator::Mul),
+            "/" => Ok(Operator::Div),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Operator {
+    pub(crate) fn apply<T>(self, left: T, right: T) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        match self {
+            Operator::Add => left + right,
+            Operator::Sub => left - right,
+            Operator::Mul => left * right,
+            Operator::Div => left / right,
+        }
+    }
+
+    /// The left operand for which `apply` gives `result`.
+    #[allow(dead_code)]
+    pub(crate) fn solve_left<T>(self, result: T, right: T) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        match self {
+            Operator::Add => result - right,
+            Operator::Sub => result + right,
+            Operator::Mul => result / right,
+            Operator::Div => result * right,
+        }
+    }
+
+    /// The right operand for which `apply` gives `result`.
+    #[allow(dead_code)]
+    pub(crate) fn solve_right<T>(self, result: T, left: T) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        match self {
+            Operator::Add => result - left,
+            Operator::Sub => left - result,
+            Operator::Mul => result / left,
+            Operator::Div => left / result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operator::Operator;
+
+    #[test]
+    fn inverse() {
+        for (operator, left, right) in [
+            (Operator::Add, 12, 4),
+            (Operator::Sub, 12, 4),
+            (Operator::Mul, 12, 4),
+            (Operator::Div, 12, 4),
+        ] {
+            let result = operator.apply(left, right);
+            assert_eq!(operator.solve_left(result, right), left);
+            assert_eq!(operator.solve_right(result, left), right);
+        }
+        assert_eq!("*".parse(), Ok(Operator::Mul));
+        assert_eq!("%".parse::<Operator>(), Err(()));
+    }
+}
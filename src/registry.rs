@@ -0,0 +1,279 @@
+//! Every solved day, looked up by number instead of calling each module by hand.
+
+use std::{any::Any, fmt, panic};
+
+/// Solvers are stored type-erased, since every day answers with a different type.
+pub type Solver = fn(&str) -> String;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const PARTS: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl TryFrom<u32> for Part {
+    type Error = u32;
+
+    fn try_from(part: u32) -> Result<Self, Self::Error> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            part => Err(part),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "Part 1"),
+            Part::Two => write!(f, "Part 2"),
+        }
+    }
+}
+
+pub struct Day {
+    pub number: u32,
+    pub title: &'static str,
+    pub input: &'static str,
+    pub part1: Solver,
+    pub part2: Solver,
+    /// Draws the visual output of days that have one.
+    pub render: Option<Solver>,
+    /// Describes the input in more depth than the answers, for days that have a report.
+    pub report: Option<Solver>,
+    /// Other implementations of the same day, which must give the same answers.
+    pub variants: &'static [Variant],
+}
+
+pub struct Variant {
+    pub name: &'static str,
+    pub part1: Solver,
+    pub part2: Solver,
+}
+
+impl Variant {
+    #[must_use]
+    pub fn solver(&self, part: Part) -> Solver {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+}
+
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Day")
+            .field("number", &self.number)
+            .field("title", &self.title)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Day {
+    #[must_use]
+    pub fn solver(&self, part: Part) -> Solver {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+
+    #[must_use]
+    pub fn solve(&self, part: Part, input: &str) -> String {
+        self.solver(part)(input)
+    }
+
+    /// Like [`Day::solve`], but turns a panic of the solver into its message.
+    ///
+    /// # Errors
+    ///
+    /// Returns the panic message if the solver panicked, usually because of malformed input.
+    pub fn try_solve(&self, part: Part, input: &str) -> Result<String, String> {
+        catch_panic(self.solver(part), input)
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "solver panicked".to_owned()
+    }
+}
+
+/// Runs `solver`, turning a panic into its message.
+/// Solvers assume well-formed puzzle input and panic otherwise.
+///
+/// # Errors
+///
+/// Returns the panic message if the solver panicked.
+pub fn catch_panic(solver: Solver, input: &str) -> Result<String, String> {
+    panic::catch_unwind(|| solver(input)).map_err(|payload| panic_message(&*payload))
+}
+
+// Unused only when every day is disabled.
+#[allow(unused_macros)]
+macro_rules! day {
+    ($module:ident, $number:literal, $title:literal $(, $hook:ident: $solver:expr)* $(,)?) => {
+        Day {
+            $($hook: $solver,)*
+            ..Day {
+                number: $number,
+                title: $title,
+                input: include_str!(concat!("../day", stringify!($number), ".txt")),
+                part1: |input| crate::$module::solve_part1(input).to_string(),
+                part2: |input| crate::$module::solve_part2(input).to_string(),
+                render: None,
+                report: None,
+                variants: &[],
+            }
+        }
+    };
+}
+
+/// Only the days enabled through their cargo feature are registered.
+pub static DAYS: &[Day] = &[
+    #[cfg(feature = "day01")]
+    day!(
+        day01,
+        1,
+        "Counting Calories",
+        report: Some(crate::day01::report)
+    ),
+    #[cfg(feature = "day02")]
+    day!(
+        day02,
+        2,
+        "Rock Paper Scissors",
+        report: Some(crate::day02::report),
+        variants: &[Variant {
+            name: "Lookup table",
+            part1: |input| crate::day02::solve_fast(input).0.to_string(),
+            part2: |input| crate::day02::solve_fast(input).1.to_string(),
+        }]
+    ),
+    #[cfg(feature = "day03")]
+    day!(day03, 3, "Rucksack Reorganization"),
+    #[cfg(feature = "day04")]
+    day!(day04, 4, "Camp Cleanup"),
+    #[cfg(feature = "day05")]
+    day!(day05, 5, "Supply Stacks"),
+    #[cfg(feature = "day06")]
+    day!(day06, 6, "Tuning Trouble"),
+    #[cfg(feature = "day07")]
+    day!(day07, 7, "No Space Left On Device"),
+    #[cfg(feature = "day08")]
+    day!(day08, 8, "Treetop Tree House"),
+    #[cfg(feature = "day09")]
+    day!(day09, 9, "Rope Bridge"),
+    #[cfg(feature = "day10")]
+    day!(
+        day10,
+        10,
+        "Cathode-Ray Tube",
+        render: Some(crate::day10::solve_part2)
+    ),
+    #[cfg(feature = "day11")]
+    day!(day11, 11, "Monkey in the Middle"),
+    #[cfg(feature = "day12")]
+    day!(day12, 12, "Hill Climbing Algorithm"),
+    #[cfg(feature = "day13")]
+    day!(day13, 13, "Distress Signal"),
+    #[cfg(feature = "day14")]
+    day!(
+        day14,
+        14,
+        "Regolith Reservoir",
+        render: Some(crate::day14::render)
+    ),
+    #[cfg(feature = "day15")]
+    day!(day15, 15, "Beacon Exclusion Zone"),
+    #[cfg(feature = "day16")]
+    day!(day16, 16, "Proboscidea Volcanium"),
+    #[cfg(feature = "day17")]
+    day!(
+        day17,
+        17,
+        "Pyroclastic Flow",
+        render: Some(crate::day17::render)
+    ),
+    #[cfg(feature = "day18")]
+    day!(day18, 18, "Boiling Boulders"),
+    #[cfg(feature = "day19")]
+    day!(day19, 19, "Not Enough Minerals"),
+    #[cfg(feature = "day20")]
+    day!(day20, 20, "Grove Positioning System"),
+    #[cfg(feature = "day21")]
+    day!(day21, 21, "Monkey Math"),
+    #[cfg(feature = "day22")]
+    day!(day22, 22, "Monkey Map"),
+    #[cfg(feature = "day23")]
+    day!(
+        day23,
+        23,
+        "Unstable Diffusion",
+        render: Some(crate::day23::render)
+    ),
+    #[cfg(feature = "day24")]
+    day!(day24, 24, "Blizzard Basin"),
+    #[cfg(feature = "day25")]
+    day!(day25, 25, "Full of Hot Air"),
+];
+
+#[must_use]
+pub fn get(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::{get, Part, DAYS};
+
+    #[test]
+    fn days_are_ordered() {
+        assert!(DAYS.windows(2).all(|days| days[0].number < days[1].number));
+    }
+
+    #[test]
+    fn lookup() {
+        for day in DAYS {
+            assert_eq!(get(day.number).unwrap().title, day.title);
+        }
+        assert!(get(0).is_none());
+    }
+
+    #[test]
+    fn variants() {
+        for day in DAYS {
+            for variant in day.variants {
+                for part in Part::PARTS {
+                    assert_eq!(
+                        variant.solver(part)(day.input),
+                        day.solve(part, day.input),
+                        "{} of day {} ({})",
+                        part,
+                        day.number,
+                        variant.name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "day02")]
+    fn solve() {
+        let day = get(2).unwrap();
+        assert_eq!(day.title, "Rock Paper Scissors");
+        assert_eq!(day.solve(Part::One, "A Y\nB X\nC Z"), "15");
+        assert_eq!(day.solve(Part::Two, "A Y\nB X\nC Z"), "12");
+    }
+}
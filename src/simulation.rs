@@ -0,0 +1,175 @@
+//! A common interface for the days that simulate state step by step,
+//! so they can be driven, inspected and rewound the same way.
+
+use std::collections::VecDeque;
+
+pub trait Simulation {
+    /// Everything needed to put the simulation back into an earlier state.
+    type Snapshot: Clone;
+
+    fn step(&mut self);
+
+    fn is_done(&self) -> bool;
+
+    fn snapshot(&self) -> Self::Snapshot;
+
+    fn restore(&mut self, snapshot: Self::Snapshot);
+
+    fn run_to_end(&mut self) {
+        while !self.is_done() {
+            self.step();
+        }
+    }
+}
+
+/// Drives a [`Simulation`] while keeping the snapshots of the last `capacity` steps.
+pub struct Recorder<S: Simulation> {
+    simulation: S,
+    // `history[i]` is the state after `steps - history.len() + i` steps.
+    history: VecDeque<S::Snapshot>,
+    capacity: usize,
+    steps: usize,
+}
+
+impl<S: Simulation> Recorder<S> {
+    pub fn new(simulation: S, capacity: usize) -> Self {
+        Self {
+            simulation,
+            history: VecDeque::with_capacity(capacity),
+            capacity,
+            steps: 0,
+        }
+    }
+
+    /// Steps the simulation once, returning `false` if it was already done.
+    pub fn step(&mut self) -> bool {
+        if self.simulation.is_done() {
+            return false;
+        }
+        if self.capacity > 0 {
+            if self.history.len() == self.capacity {
+                self.history.pop_front();
+            }
+            self.history.push_back(self.simulation.snapshot());
+        }
+        self.simulation.step();
+        self.steps += 1;
+        true
+    }
+
+    pub fn run_to_end(&mut self) {
+        while self.step() {}
+    }
+
+    /// The number of steps taken so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// The earliest step that can still be inspected or rewound to.
+    pub fn oldest_step(&self) -> usize {
+        self.steps - self.history.len()
+    }
+
+    /// The state after `step` steps, if it is still in the history.
+    /// The current state is available through [`Recorder::simulation`].
+    pub fn get(&self, step: usize) -> Option<&S::Snapshot> {
+        self.history.get(step.checked_sub(self.oldest_step())?)
+    }
+
+    /// Puts the simulation back into the state after `step` steps,
+    /// forgetting everything recorded after it.
+    /// Returns `false` if that step is no longer in the history.
+    pub fn rewind_to(&mut self, step: usize) -> bool {
+        let Some(index) = step.checked_sub(self.oldest_step()) else {
+            return false;
+        };
+        if index > self.history.len() {
+            return false;
+        }
+        // Draining nothing means `step` is the current one.
+        if let Some(snapshot) = self.history.drain(index..).next() {
+            self.simulation.restore(snapshot);
+            self.steps = step;
+        }
+        true
+    }
+
+    pub fn simulation(&self) -> &S {
+        &self.simulation
+    }
+
+    pub fn into_inner(self) -> S {
+        self.simulation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulation::{Recorder, Simulation};
+
+    struct Counter {
+        count: u32,
+        limit: u32,
+    }
+
+    impl Simulation for Counter {
+        type Snapshot = u32;
+
+        fn step(&mut self) {
+            self.count += 1;
+        }
+
+        fn is_done(&self) -> bool {
+            self.count == self.limit
+        }
+
+        fn snapshot(&self) -> Self::Snapshot {
+            self.count
+        }
+
+        fn restore(&mut self, snapshot: Self::Snapshot) {
+            self.count = snapshot;
+        }
+    }
+
+    #[test]
+    fn bounded_history() {
+        let mut recorder = Recorder::new(
+            Counter {
+                count: 0,
+                limit: 10,
+            },
+            3,
+        );
+        recorder.run_to_end();
+        assert_eq!(recorder.steps(), 10);
+        assert!(!recorder.step());
+        assert_eq!(recorder.oldest_step(), 7);
+        assert_eq!(recorder.get(6), None);
+        assert_eq!(recorder.get(7), Some(&7));
+        assert_eq!(recorder.get(9), Some(&9));
+        assert_eq!(recorder.get(10), None);
+    }
+
+    #[test]
+    fn rewind() {
+        let mut recorder = Recorder::new(
+            Counter {
+                count: 0,
+                limit: 10,
+            },
+            5,
+        );
+        recorder.run_to_end();
+        assert!(!recorder.rewind_to(2));
+        assert!(recorder.rewind_to(10));
+        assert!(recorder.rewind_to(8));
+        assert_eq!(recorder.simulation().count, 8);
+        assert_eq!(recorder.steps(), 8);
+        assert_eq!(recorder.get(8), None);
+        assert!(recorder.step());
+        assert_eq!(recorder.get(8), Some(&8));
+        assert_eq!(recorder.into_inner().count, 9);
+    }
+}
@@ -0,0 +1,40 @@
+#![cfg(all(feature = "day02", feature = "day06"))]
+
+use std::{env, path::PathBuf, process::Command};
+
+/// Compiles `tests/ffi/harness.c` against the `cdylib` and runs it.
+#[test]
+fn c_harness() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // Integration tests are built into `target/<profile>/deps`, next to the `cdylib`.
+    let library_dir = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let harness = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("ffi_harness");
+
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_owned());
+    let status = Command::new(compiler)
+        .arg(manifest_dir.join("tests/ffi/harness.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(&library_dir)
+        .arg(format!("-Wl,-rpath,{}", library_dir.display()))
+        .arg("-laoc2022_rs")
+        .arg("-o")
+        .arg(&harness)
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to compile the C harness");
+
+    // Cargo puts `target/<profile>` on the library path, which is searched before the rpath
+    // and may hold a library built with other features.
+    let output = Command::new(&harness)
+        .env("LD_LIBRARY_PATH", &library_dir)
+        .env("DYLD_LIBRARY_PATH", &library_dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
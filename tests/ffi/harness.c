@@ -0,0 +1,46 @@
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc2022.h"
+
+static const char DAY2[] = "A Y\nB X\nC Z";
+
+static int failures = 0;
+
+static void expect_answer(uint32_t day, uint32_t part, const char *input, const char *expected) {
+    aoc_result *result = aoc_solve(day, part, input, strlen(input));
+    if (result->status != AOC_STATUS_OK || result->message != NULL ||
+        strcmp(result->answer, expected) != 0) {
+        printf("day %u part %u: expected answer %s, got status %d\n", day, part, expected,
+               result->status);
+        failures++;
+    }
+    aoc_result_free(result);
+}
+
+static void expect_error(uint32_t day, uint32_t part, const char *input, size_t input_len,
+                         aoc_status expected) {
+    aoc_result *result = aoc_solve(day, part, input, input_len);
+    if (result->status != expected || result->answer != NULL || result->message == NULL) {
+        printf("day %u part %u: expected status %d, got %d\n", day, part, expected,
+               result->status);
+        failures++;
+    }
+    aoc_result_free(result);
+}
+
+int main(void) {
+    expect_answer(2, 1, DAY2, "15");
+    expect_answer(2, 2, DAY2, "12");
+    expect_answer(6, 1, "mjqjpqmgbljsphdztnvjfqwrcgsmlb", "7");
+
+    expect_error(99, 1, DAY2, strlen(DAY2), AOC_STATUS_UNKNOWN_DAY);
+    expect_error(2, 3, DAY2, strlen(DAY2), AOC_STATUS_INVALID_PART);
+    expect_error(2, 1, NULL, 0, AOC_STATUS_NULL_INPUT);
+    expect_error(2, 1, "\xff", 1, AOC_STATUS_INVALID_UTF8);
+    expect_error(2, 1, "Q Q", 3, AOC_STATUS_SOLVER_PANICKED);
+
+    aoc_result_free(NULL);
+
+    return failures == 0 ? 0 : 1;
+}